@@ -1,30 +1,21 @@
 #![cfg_attr(not(feature = "std"), no_std, no_main)]
 #[ink::contract]
 mod ztg_runtime_example {
-    use ztg_runtime_lib::{primitives::*, runtime_structs::*};
-    use ink::env::Error as EnvError;
+    use ztg_runtime_lib::{
+        errors::ZeitgeistError,
+        harness::{RuntimeDispatcher, ZeitgeistDispatcher},
+        primitives::*,
+        runtime_structs::*,
+    };
+    use ink::prelude::vec::Vec;
     use sp_runtime::Perbill;
 
-    #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
-    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
-    pub enum Error {
-        CallRuntimeFailed,
-    }
-
-    impl From<EnvError> for Error {
-        fn from(e: EnvError) -> Self {
-            match e {
-                EnvError::CallRuntimeFailed => Error::CallRuntimeFailed,
-                _ => panic!("Unexpected error from `pallet-contracts`."),
-            }
-        }
-    }
-
-    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Result<T> = core::result::Result<T, ZeitgeistError>;
 
     #[ink(storage)]
     pub struct ZtgRuntimeExample {
         outcome_report: OutcomeReport,
+        queued_calls: Vec<RuntimeCall>,
     }
 
     impl ZtgRuntimeExample {
@@ -32,6 +23,7 @@ mod ztg_runtime_example {
         pub fn new(_outcome: OutcomeReport) -> Self {
             ZtgRuntimeExample {
                 outcome_report: _outcome,
+                queued_calls: Vec::new(),
             }
         }
 
@@ -40,17 +32,111 @@ mod ztg_runtime_example {
             Self::new(OutcomeReport::Scalar(0))
         }
 
+        /// Dispatches calls like `self.env()`, but through `ztg_runtime_lib::harness`, so that
+        /// the messages can be tested against a `RuntimeHarness` off-chain.
+        fn runtime(&self) -> RuntimeDispatcher<Environment> {
+            RuntimeDispatcher::new()
+        }
+
+        // region: Queued Calls
+
+        /// Queues any runtime call, to be dispatched later with `dispatch_queued`.
+        #[ink(message)]
+        pub fn queue_call(&mut self, call: RuntimeCall) {
+            self.queued_calls.push(call);
+        }
+
+        /// Dispatches the queued calls in order and clears the queue.
+        #[ink(message)]
+        pub fn dispatch_queued(&mut self) -> Result<()> {
+            self.dispatch_queued_with(self.runtime())
+        }
+
+        /// Dispatches the queued calls with any dispatcher, e.g. a `RecordingDispatcher` in
+        /// unit tests.
+        pub fn dispatch_queued_with(&mut self, mut dispatcher: impl ZeitgeistDispatcher) -> Result<()> {
+            for call in core::mem::take(&mut self.queued_calls) {
+                dispatcher.dispatch(&call)?;
+            }
+            Ok(())
+        }
+
+        // endregion
+
+        // region: Utility
+
+        /// Dispatches all calls or none of them.
+        #[ink(message)]
+        pub fn utility_batch_all(&mut self, calls: Vec<RuntimeCall>) -> Result<()> {
+            let mut batch = Batch::new();
+            batch.extend(calls);
+            self.runtime()
+                .call_runtime(&batch.batch_all())
+                .map_err(Into::<ZeitgeistError>::into)
+        }
+
+        // endregion
+
         // region: Asset Manager
 
         #[ink(message)]
         pub fn transfer(&mut self, dest: AccountId, amount: Balance) -> Result<()> {
-            self.env()
+            self.runtime()
                 .call_runtime(&RuntimeCall::AssetManager(AssetManagerCall::Transfer {
                     dest: dest.into(),
                     currency_id: ZeitgeistAsset::Ztg,
                     amount,
                 }))
-                .map_err(Into::<Error>::into)
+                .map_err(Into::<ZeitgeistError>::into)
+        }
+
+        #[ink(message)]
+        pub fn transfer_native_currency(&mut self, dest: AccountId, amount: Balance) -> Result<()> {
+            self.runtime()
+                .call_runtime(&AssetManagerCall::transfer_native_currency(dest, amount))
+                .map_err(Into::<ZeitgeistError>::into)
+        }
+
+        /// @note: Requires SUDO
+        #[ink(message)]
+        pub fn update_balance(&mut self, who: AccountId, currency_id: ZeitgeistAsset, amount: i128) -> Result<()> {
+            self.runtime()
+                .call_runtime(&AssetManagerCall::update_balance(who, currency_id, amount))
+                .map_err(Into::<ZeitgeistError>::into)
+        }
+
+        // endregion
+
+        // region: Balances
+
+        #[ink(message)]
+        pub fn balances_transfer_allow_death(&mut self, dest: AccountId, value: Balance) -> Result<()> {
+            self.runtime()
+                .call_runtime(&RuntimeCall::Balances(BalancesCall::TransferAllowDeath {
+                    dest: dest.into(),
+                    value,
+                }))
+                .map_err(Into::<ZeitgeistError>::into)
+        }
+
+        #[ink(message)]
+        pub fn balances_transfer_keep_alive(&mut self, dest: AccountId, value: Balance) -> Result<()> {
+            self.runtime()
+                .call_runtime(&RuntimeCall::Balances(BalancesCall::TransferKeepAlive {
+                    dest: dest.into(),
+                    value,
+                }))
+                .map_err(Into::<ZeitgeistError>::into)
+        }
+
+        #[ink(message)]
+        pub fn balances_transfer_all(&mut self, dest: AccountId, keep_alive: bool) -> Result<()> {
+            self.runtime()
+                .call_runtime(&RuntimeCall::Balances(BalancesCall::TransferAll {
+                    dest: dest.into(),
+                    keep_alive,
+                }))
+                .map_err(Into::<ZeitgeistError>::into)
         }
 
         // endregion
@@ -64,11 +150,11 @@ mod ztg_runtime_example {
             market_id: MarketId,
             outcome: OutcomeReport,
         ) -> Result<()> {
-            self.env()
+            self.runtime()
                 .call_runtime(&RuntimeCall::Authorized(
                     AuthorizedCall::AuthorizeMarketOutcome { market_id, outcome },
                 ))
-                .map_err(Into::<Error>::into)
+                .map_err(Into::<ZeitgeistError>::into)
         }
 
         // endregion
@@ -83,13 +169,13 @@ mod ztg_runtime_example {
             pool_amount: Balance,
             min_assets_out: ink::prelude::vec::Vec<Balance>,
         ) -> Result<()> {
-            self.env()
+            self.runtime()
                 .call_runtime(&RuntimeCall::Swaps(SwapsCall::PoolExit {
                     pool_id,
                     pool_amount,
                     min_assets_out,
                 }))
-                .map_err(Into::<Error>::into)
+                .map_err(Into::<ZeitgeistError>::into)
         }
 
         /// @note: Disabled
@@ -101,7 +187,7 @@ mod ztg_runtime_example {
             asset_amount: Balance,
             max_pool_amount: Balance,
         ) -> Result<()> {
-            self.env()
+            self.runtime()
                 .call_runtime(&RuntimeCall::Swaps(
                     SwapsCall::PoolExitWithExactAssetAmount {
                         pool_id,
@@ -110,7 +196,7 @@ mod ztg_runtime_example {
                         max_pool_amount,
                     },
                 ))
-                .map_err(Into::<Error>::into)
+                .map_err(Into::<ZeitgeistError>::into)
         }
 
         /// @note: Disabled
@@ -122,7 +208,7 @@ mod ztg_runtime_example {
             pool_amount: Balance,
             min_asset_amount: Balance,
         ) -> Result<()> {
-            self.env()
+            self.runtime()
                 .call_runtime(&RuntimeCall::Swaps(
                     SwapsCall::PoolExitWithExactPoolAmount {
                         pool_id,
@@ -131,7 +217,7 @@ mod ztg_runtime_example {
                         min_asset_amount,
                     },
                 ))
-                .map_err(Into::<Error>::into)
+                .map_err(Into::<ZeitgeistError>::into)
         }
 
         /// @note: Disabled
@@ -142,13 +228,13 @@ mod ztg_runtime_example {
             pool_amount: Balance,
             max_assets_in: ink::prelude::vec::Vec<Balance>,
         ) -> Result<()> {
-            self.env()
+            self.runtime()
                 .call_runtime(&RuntimeCall::Swaps(SwapsCall::PoolJoin {
                     pool_id,
                     pool_amount,
                     max_assets_in,
                 }))
-                .map_err(Into::<Error>::into)
+                .map_err(Into::<ZeitgeistError>::into)
         }
 
         /// @note: Disabled
@@ -160,7 +246,7 @@ mod ztg_runtime_example {
             asset_amount: Balance,
             min_pool_amount: Balance,
         ) -> Result<()> {
-            self.env()
+            self.runtime()
                 .call_runtime(&RuntimeCall::Swaps(
                     SwapsCall::PoolJoinWithExactAssetAmount {
                         pool_id,
@@ -169,7 +255,7 @@ mod ztg_runtime_example {
                         min_pool_amount,
                     },
                 ))
-                .map_err(Into::<Error>::into)
+                .map_err(Into::<ZeitgeistError>::into)
         }
 
         /// @note: Disabled
@@ -181,7 +267,7 @@ mod ztg_runtime_example {
             pool_amount: Balance,
             max_asset_amount: Balance,
         ) -> Result<()> {
-            self.env()
+            self.runtime()
                 .call_runtime(&RuntimeCall::Swaps(
                     SwapsCall::PoolJoinWithExactPoolAmount {
                         pool_id,
@@ -190,7 +276,7 @@ mod ztg_runtime_example {
                         max_asset_amount,
                     },
                 ))
-                .map_err(Into::<Error>::into)
+                .map_err(Into::<ZeitgeistError>::into)
         }
 
         /// @note: Disabled
@@ -204,7 +290,7 @@ mod ztg_runtime_example {
             min_asset_amount_out: Option<Balance>,
             max_price: Option<Balance>,
         ) -> Result<()> {
-            self.env()
+            self.runtime()
                 .call_runtime(&RuntimeCall::Swaps(SwapsCall::SwapExactAmountIn {
                     pool_id,
                     asset_in,
@@ -213,7 +299,7 @@ mod ztg_runtime_example {
                     min_asset_amount_out,
                     max_price,
                 }))
-                .map_err(Into::<Error>::into)
+                .map_err(Into::<ZeitgeistError>::into)
         }
 
         /// @note: Disabled
@@ -227,7 +313,7 @@ mod ztg_runtime_example {
             asset_amount_out: u128,
             max_price: Option<u128>,
         ) -> Result<()> {
-            self.env()
+            self.runtime()
                 .call_runtime(&RuntimeCall::Swaps(SwapsCall::SwapExactAmountOut {
                     pool_id,
                     asset_in,
@@ -236,7 +322,7 @@ mod ztg_runtime_example {
                     asset_amount_out,
                     max_price,
                 }))
-                .map_err(Into::<Error>::into)
+                .map_err(Into::<ZeitgeistError>::into)
         }
 
         #[ink(message)]
@@ -247,14 +333,14 @@ mod ztg_runtime_example {
             pool_amount: Balance,
             min_assets_out: ink::prelude::vec::Vec<Balance>,
         ) -> Result<()> {
-            self.env()
+            self.runtime()
                 .call_runtime(&RuntimeCall::Swaps(SwapsCall::ForcePoolExit {
                     who,
                     pool_id,
                     pool_amount,
                     min_assets_out,
                 }))
-                .map_err(Into::<Error>::into)
+                .map_err(Into::<ZeitgeistError>::into)
         }
 
         // endregion
@@ -263,9 +349,9 @@ mod ztg_runtime_example {
 
         #[ink(message)]
         pub fn join_court(&mut self, amount: Balance) -> Result<()> {
-            self.env()
+            self.runtime()
                 .call_runtime(&RuntimeCall::Court(CourtCall::JoinCourt { amount }))
-                .map_err(Into::<Error>::into)
+                .map_err(Into::<ZeitgeistError>::into)
         }
 
         #[ink(message)]
@@ -274,38 +360,38 @@ mod ztg_runtime_example {
             amount: Balance,
             delegations: ink::prelude::vec::Vec<AccountId>,
         ) -> Result<()> {
-            self.env()
+            self.runtime()
                 .call_runtime(&RuntimeCall::Court(CourtCall::Delegate {
                     amount,
                     delegations,
                 }))
-                .map_err(Into::<Error>::into)
+                .map_err(Into::<ZeitgeistError>::into)
         }
 
         #[ink(message)]
         pub fn prepare_exit_court(&mut self) -> Result<()> {
-            self.env()
+            self.runtime()
                 .call_runtime(&RuntimeCall::Court(CourtCall::PrepareExitCourt))
-                .map_err(Into::<Error>::into)
+                .map_err(Into::<ZeitgeistError>::into)
         }
 
         #[ink(message)]
         pub fn exit_court(&mut self, court_participant: AccountId) -> Result<()> {
-            self.env()
+            self.runtime()
                 .call_runtime(&RuntimeCall::Court(CourtCall::ExitCourt {
                     court_participant: court_participant.into(),
                 }))
-                .map_err(Into::<Error>::into)
+                .map_err(Into::<ZeitgeistError>::into)
         }
 
         #[ink(message)]
         pub fn vote(&mut self, court_id: CourtId, commitment_vote: CourtHash) -> Result<()> {
-            self.env()
+            self.runtime()
                 .call_runtime(&RuntimeCall::Court(CourtCall::Vote {
                     court_id,
                     commitment_vote,
                 }))
-                .map_err(Into::<Error>::into)
+                .map_err(Into::<ZeitgeistError>::into)
         }
 
         #[ink(message)]
@@ -316,14 +402,14 @@ mod ztg_runtime_example {
             vote_item: VoteItem,
             salt: CourtHash,
         ) -> Result<()> {
-            self.env()
+            self.runtime()
                 .call_runtime(&RuntimeCall::Court(CourtCall::DenounceVote {
                     court_id,
                     juror: juror.into(),
                     vote_item,
                     salt,
                 }))
-                .map_err(Into::<Error>::into)
+                .map_err(Into::<ZeitgeistError>::into)
         }
 
         #[ink(message)]
@@ -333,37 +419,37 @@ mod ztg_runtime_example {
             vote_item: VoteItem,
             salt: CourtHash,
         ) -> Result<()> {
-            self.env()
+            self.runtime()
                 .call_runtime(&RuntimeCall::Court(CourtCall::RevealVote {
                     court_id,
                     vote_item,
                     salt,
                 }))
-                .map_err(Into::<Error>::into)
+                .map_err(Into::<ZeitgeistError>::into)
         }
 
         #[ink(message)]
         pub fn appeal(&mut self, court_id: CourtId) -> Result<()> {
-            self.env()
+            self.runtime()
                 .call_runtime(&RuntimeCall::Court(CourtCall::Appeal { court_id }))
-                .map_err(Into::<Error>::into)
+                .map_err(Into::<ZeitgeistError>::into)
         }
 
         #[ink(message)]
         pub fn reassign_court_stakes(&mut self, court_id: CourtId) -> Result<()> {
-            self.env()
+            self.runtime()
                 .call_runtime(&RuntimeCall::Court(CourtCall::ReassignCourtStakes {
                     court_id,
                 }))
-                .map_err(Into::<Error>::into)
+                .map_err(Into::<ZeitgeistError>::into)
         }
 
         /// @note: Requires SUDO
         #[ink(message)]
         pub fn set_inflation(&mut self, inflation: Perbill) -> Result<()> {
-            self.env()
+            self.runtime()
                 .call_runtime(&RuntimeCall::Court(CourtCall::SetInflation { inflation }))
-                .map_err(Into::<Error>::into)
+                .map_err(Into::<ZeitgeistError>::into)
         }
 
         // endregion
@@ -373,16 +459,16 @@ mod ztg_runtime_example {
         /// @note: Requires SUDO
         #[ink(message)]
         pub fn set_burn_amount(&mut self, amount: Balance) -> Result<()> {
-            self.env()
+            self.runtime()
                 .call_runtime(&RuntimeCall::Styx(StyxCall::SetBurnAmount { amount }))
-                .map_err(Into::<Error>::into)
+                .map_err(Into::<ZeitgeistError>::into)
         }
 
         #[ink(message)]
         pub fn cross(&mut self) -> Result<()> {
-            self.env()
+            self.runtime()
                 .call_runtime(&RuntimeCall::Styx(StyxCall::Cross))
-                .map_err(Into::<Error>::into)
+                .map_err(Into::<ZeitgeistError>::into)
         }
 
         // endregion
@@ -395,29 +481,29 @@ mod ztg_runtime_example {
             market_id: MarketId,
             outcome: OutcomeReport,
         ) -> Result<()> {
-            self.env()
+            self.runtime()
                 .call_runtime(&RuntimeCall::GlobalDisputes(
                     GlobalDisputesCall::AddVoteOutcome { market_id, outcome },
                 ))
-                .map_err(Into::<Error>::into)
+                .map_err(Into::<ZeitgeistError>::into)
         }
 
         #[ink(message)]
         pub fn purge_outcomes(&mut self, market_id: MarketId) -> Result<()> {
-            self.env()
+            self.runtime()
                 .call_runtime(&RuntimeCall::GlobalDisputes(
                     GlobalDisputesCall::PurgeOutcomes { market_id },
                 ))
-                .map_err(Into::<Error>::into)
+                .map_err(Into::<ZeitgeistError>::into)
         }
 
         #[ink(message)]
         pub fn reward_outcome_owner(&mut self, market_id: MarketId) -> Result<()> {
-            self.env()
+            self.runtime()
                 .call_runtime(&RuntimeCall::GlobalDisputes(
                     GlobalDisputesCall::RewardOutcomeOwner { market_id },
                 ))
-                .map_err(Into::<Error>::into)
+                .map_err(Into::<ZeitgeistError>::into)
         }
 
         #[ink(message)]
@@ -427,7 +513,7 @@ mod ztg_runtime_example {
             outcome: OutcomeReport,
             amount: Balance,
         ) -> Result<()> {
-            self.env()
+            self.runtime()
                 .call_runtime(&RuntimeCall::GlobalDisputes(
                     GlobalDisputesCall::VoteOnOutcome {
                         market_id,
@@ -435,27 +521,27 @@ mod ztg_runtime_example {
                         amount,
                     },
                 ))
-                .map_err(Into::<Error>::into)
+                .map_err(Into::<ZeitgeistError>::into)
         }
 
         #[ink(message)]
         pub fn unlock_vote_balance(&mut self, voter: AccountId) -> Result<()> {
-            self.env()
+            self.runtime()
                 .call_runtime(&RuntimeCall::GlobalDisputes(
                     GlobalDisputesCall::UnlockVoteBalance {
                         voter: voter.into(),
                     },
                 ))
-                .map_err(Into::<Error>::into)
+                .map_err(Into::<ZeitgeistError>::into)
         }
 
         #[ink(message)]
         pub fn refund_vote_fees(&mut self, market_id: MarketId) -> Result<()> {
-            self.env()
+            self.runtime()
                 .call_runtime(&RuntimeCall::GlobalDisputes(
                     GlobalDisputesCall::RefundVoteFees { market_id },
                 ))
-                .map_err(Into::<Error>::into)
+                .map_err(Into::<ZeitgeistError>::into)
         }
 
         // endregion
@@ -465,31 +551,31 @@ mod ztg_runtime_example {
         /// @note: Requires SUDO
         #[ink(message)]
         pub fn admin_move_market_to_closed(&mut self, market_id: MarketId) -> Result<()> {
-            self.env()
+            self.runtime()
                 .call_runtime(&RuntimeCall::PredictionMarkets(
                     PredictionMarketsCall::AdminMoveMarketToClosed { market_id },
                 ))
-                .map_err(Into::<Error>::into)
+                .map_err(Into::<ZeitgeistError>::into)
         }
 
         /// @note: Requires SUDO
         #[ink(message)]
         pub fn admin_move_market_to_resolved(&mut self, market_id: MarketId) -> Result<()> {
-            self.env()
+            self.runtime()
                 .call_runtime(&RuntimeCall::PredictionMarkets(
                     PredictionMarketsCall::AdminMoveMarketToResolved { market_id },
                 ))
-                .map_err(Into::<Error>::into)
+                .map_err(Into::<ZeitgeistError>::into)
         }
 
         /// @note: Requires SUDO
         #[ink(message)]
         pub fn approve_market(&mut self, market_id: MarketId) -> Result<()> {
-            self.env()
+            self.runtime()
                 .call_runtime(&RuntimeCall::PredictionMarkets(
                     PredictionMarketsCall::ApproveMarket { market_id },
                 ))
-                .map_err(Into::<Error>::into)
+                .map_err(Into::<ZeitgeistError>::into)
         }
 
         /// @note: Requires SUDO
@@ -499,35 +585,36 @@ mod ztg_runtime_example {
             market_id: MarketId,
             edit_reason: ink::prelude::vec::Vec<u8>,
         ) -> Result<()> {
-            self.env()
+            self.runtime()
                 .call_runtime(&RuntimeCall::PredictionMarkets(
                     PredictionMarketsCall::RequestEdit {
                         market_id,
                         edit_reason,
                     },
                 ))
-                .map_err(Into::<Error>::into)
+                .map_err(Into::<ZeitgeistError>::into)
         }
 
         #[ink(message)]
         pub fn buy_complete_set(&mut self, market_id: MarketId, amount: Balance) -> Result<()> {
-            self.env()
+            self.runtime()
                 .call_runtime(&RuntimeCall::PredictionMarkets(
                     PredictionMarketsCall::BuyCompleteSet { market_id, amount },
                 ))
-                .map_err(Into::<Error>::into)
+                .map_err(Into::<ZeitgeistError>::into)
         }
 
         #[ink(message)]
         pub fn dispute(&mut self, market_id: MarketId) -> Result<()> {
-            self.env()
+            self.runtime()
                 .call_runtime(&RuntimeCall::PredictionMarkets(
                     PredictionMarketsCall::Dispute { market_id },
                 ))
-                .map_err(Into::<Error>::into)
+                .map_err(Into::<ZeitgeistError>::into)
         }
 
         #[ink(message)]
+        #[allow(clippy::too_many_arguments)]
         pub fn create_market(
             &mut self,
             base_asset: ZeitgeistAsset,
@@ -541,7 +628,7 @@ mod ztg_runtime_example {
             dispute_mechanism: Option<MarketDisputeMechanism>,
             scoring_rule: ScoringRule,
         ) -> Result<()> {
-            self.env()
+            self.runtime()
                 .call_runtime(&RuntimeCall::PredictionMarkets(
                     PredictionMarketsCall::CreateMarket {
                         base_asset,
@@ -556,10 +643,11 @@ mod ztg_runtime_example {
                         scoring_rule,
                     },
                 ))
-                .map_err(Into::<Error>::into)
+                .map_err(Into::<ZeitgeistError>::into)
         }
 
         #[ink(message)]
+        #[allow(clippy::too_many_arguments)]
         pub fn edit_market(
             &mut self,
             base_asset: ZeitgeistAsset,
@@ -572,7 +660,7 @@ mod ztg_runtime_example {
             dispute_mechanism: Option<MarketDisputeMechanism>,
             scoring_rule: ScoringRule,
         ) -> Result<()> {
-            self.env()
+            self.runtime()
                 .call_runtime(&RuntimeCall::PredictionMarkets(
                     PredictionMarketsCall::EditMarket {
                         base_asset,
@@ -586,16 +674,16 @@ mod ztg_runtime_example {
                         scoring_rule,
                     },
                 ))
-                .map_err(Into::<Error>::into)
+                .map_err(Into::<ZeitgeistError>::into)
         }
 
         #[ink(message)]
         pub fn redeem_shares(&mut self, market_id: MarketId) -> Result<()> {
-            self.env()
+            self.runtime()
                 .call_runtime(&RuntimeCall::PredictionMarkets(
                     PredictionMarketsCall::RedeemShares { market_id },
                 ))
-                .map_err(Into::<Error>::into)
+                .map_err(Into::<ZeitgeistError>::into)
         }
 
         /// @note: Requires SUDO
@@ -605,44 +693,45 @@ mod ztg_runtime_example {
             market_id: MarketId,
             reject_reason: ink::prelude::vec::Vec<u8>,
         ) -> Result<()> {
-            self.env()
+            self.runtime()
                 .call_runtime(&RuntimeCall::PredictionMarkets(
                     PredictionMarketsCall::RejectMarket {
                         market_id,
                         reject_reason,
                     },
                 ))
-                .map_err(Into::<Error>::into)
+                .map_err(Into::<ZeitgeistError>::into)
         }
 
         #[ink(message)]
         pub fn report(&mut self, market_id: MarketId, outcome: OutcomeReport) -> Result<()> {
-            self.env()
+            self.runtime()
                 .call_runtime(&RuntimeCall::PredictionMarkets(
                     PredictionMarketsCall::Report { market_id, outcome },
                 ))
-                .map_err(Into::<Error>::into)
+                .map_err(Into::<ZeitgeistError>::into)
         }
 
         #[ink(message)]
         pub fn sell_complete_set(&mut self, market_id: MarketId, amount: Balance) -> Result<()> {
-            self.env()
+            self.runtime()
                 .call_runtime(&RuntimeCall::PredictionMarkets(
                     PredictionMarketsCall::SellCompleteSet { market_id, amount },
                 ))
-                .map_err(Into::<Error>::into)
+                .map_err(Into::<ZeitgeistError>::into)
         }
 
         #[ink(message)]
         pub fn start_global_dispute(&mut self, market_id: MarketId) -> Result<()> {
-            self.env()
+            self.runtime()
                 .call_runtime(&RuntimeCall::PredictionMarkets(
                     PredictionMarketsCall::StartGlobalDispute { market_id },
                 ))
-                .map_err(Into::<Error>::into)
+                .map_err(Into::<ZeitgeistError>::into)
         }
 
         #[ink(message)]
+        #[allow(clippy::too_many_arguments)]
         pub fn create_market_and_deploy_pool(
             &mut self,
             base_asset: ZeitgeistAsset,
@@ -657,7 +746,7 @@ mod ztg_runtime_example {
             spot_prices: ink::prelude::vec::Vec<Balance>,
             swap_fee: Balance,
         ) -> Result<()> {
-            self.env()
+            self.runtime()
                 .call_runtime(&RuntimeCall::PredictionMarkets(
                     PredictionMarketsCall::CreateMarketAndDeployPool {
                         base_asset,
@@ -673,52 +762,52 @@ mod ztg_runtime_example {
                         swap_fee,
                     },
                 ))
-                .map_err(Into::<Error>::into)
+                .map_err(Into::<ZeitgeistError>::into)
         }
 
         #[ink(message)]
         pub fn schedule_early_close(&mut self, market_id: MarketId) -> Result<()> {
-            self.env()
+            self.runtime()
                 .call_runtime(&RuntimeCall::PredictionMarkets(
                     PredictionMarketsCall::ScheduleEarlyClose { market_id },
                 ))
-                .map_err(Into::<Error>::into)
+                .map_err(Into::<ZeitgeistError>::into)
         }
 
         #[ink(message)]
         pub fn dispute_early_close(&mut self, market_id: MarketId) -> Result<()> {
-            self.env()
+            self.runtime()
                 .call_runtime(&RuntimeCall::PredictionMarkets(
                     PredictionMarketsCall::DisputeEarlyClose { market_id },
                 ))
-                .map_err(Into::<Error>::into)
+                .map_err(Into::<ZeitgeistError>::into)
         }
 
         #[ink(message)]
         pub fn reject_early_close(&mut self, market_id: MarketId) -> Result<()> {
-            self.env()
+            self.runtime()
                 .call_runtime(&RuntimeCall::PredictionMarkets(
                     PredictionMarketsCall::RejectEarlyClose { market_id },
                 ))
-                .map_err(Into::<Error>::into)
+                .map_err(Into::<ZeitgeistError>::into)
         }
 
         #[ink(message)]
         pub fn close_trusted_market(&mut self, market_id: MarketId) -> Result<()> {
-            self.env()
+            self.runtime()
                 .call_runtime(&RuntimeCall::PredictionMarkets(
                     PredictionMarketsCall::CloseTrustedMarket { market_id },
                 ))
-                .map_err(Into::<Error>::into)
+                .map_err(Into::<ZeitgeistError>::into)
         }
 
         #[ink(message)]
         pub fn manually_close_market(&mut self, market_id: MarketId) -> Result<()> {
-            self.env()
+            self.runtime()
                 .call_runtime(&RuntimeCall::PredictionMarkets(
                     PredictionMarketsCall::ManuallyCloseMarket { market_id },
                 ))
-                .map_err(Into::<Error>::into)
+                .map_err(Into::<ZeitgeistError>::into)
         }
 
         // endregion
@@ -734,7 +823,7 @@ mod ztg_runtime_example {
             amount_in: Balance,
             min_amount_out: Balance,
         ) -> Result<()> {
-            self.env()
+            self.runtime()
                 .call_runtime(&RuntimeCall::NeoSwaps(NeoSwapsCall::Buy {
                     market_id,
                     asset_count,
@@ -742,7 +831,7 @@ mod ztg_runtime_example {
                     amount_in,
                     min_amount_out,
                 }))
-                .map_err(Into::<Error>::into)
+                .map_err(Into::<ZeitgeistError>::into)
         }
 
         #[ink(message)]
@@ -754,7 +843,7 @@ mod ztg_runtime_example {
             amount_in: Balance,
             min_amount_out: Balance,
         ) -> Result<()> {
-            self.env()
+            self.runtime()
                 .call_runtime(&RuntimeCall::NeoSwaps(NeoSwapsCall::Sell {
                     market_id,
                     asset_count,
@@ -762,7 +851,45 @@ mod ztg_runtime_example {
                     amount_in,
                     min_amount_out,
                 }))
-                .map_err(Into::<Error>::into)
+                .map_err(Into::<ZeitgeistError>::into)
+        }
+
+        /// Buys with `min_amount_out` and `asset_count` computed from a snapshot of the pool.
+        #[ink(message)]
+        pub fn neoswap_buy_with_max_slippage(
+            &mut self,
+            market_id: MarketId,
+            pool: NeoSwapsPool,
+            asset_out: ZeitgeistAsset,
+            amount_in: Balance,
+            creator_fee: Perbill,
+            slippage_bps: u16,
+        ) -> Result<()> {
+            let call = pool
+                .buy_with_max_slippage(market_id, asset_out, amount_in, creator_fee, slippage_bps)
+                .map_err(ZeitgeistError::NeoSwaps)?;
+            self.runtime()
+                .call_runtime(&call)
+                .map_err(Into::<ZeitgeistError>::into)
+        }
+
+        /// Sells with `min_amount_out` and `asset_count` computed from a snapshot of the pool.
+        #[ink(message)]
+        pub fn neoswap_sell_with_max_slippage(
+            &mut self,
+            market_id: MarketId,
+            pool: NeoSwapsPool,
+            asset_in: ZeitgeistAsset,
+            amount_in: Balance,
+            creator_fee: Perbill,
+            slippage_bps: u16,
+        ) -> Result<()> {
+            let call = pool
+                .sell_with_max_slippage(market_id, asset_in, amount_in, creator_fee, slippage_bps)
+                .map_err(ZeitgeistError::NeoSwaps)?;
+            self.runtime()
+                .call_runtime(&call)
+                .map_err(Into::<ZeitgeistError>::into)
         }
 
         #[ink(message)]
@@ -772,13 +899,13 @@ mod ztg_runtime_example {
             pool_shares_amount: Balance,
             max_amounts_in: ink::prelude::vec::Vec<Balance>,
         ) -> Result<()> {
-            self.env()
+            self.runtime()
                 .call_runtime(&RuntimeCall::NeoSwaps(NeoSwapsCall::Join {
                     market_id,
                     pool_shares_amount,
                     max_amounts_in,
                 }))
-                .map_err(Into::<Error>::into)
+                .map_err(Into::<ZeitgeistError>::into)
         }
 
         #[ink(message)]
@@ -788,22 +915,22 @@ mod ztg_runtime_example {
             pool_shares_amount_out: Balance,
             min_amounts_out: ink::prelude::vec::Vec<Balance>,
         ) -> Result<()> {
-            self.env()
+            self.runtime()
                 .call_runtime(&RuntimeCall::NeoSwaps(NeoSwapsCall::Exit {
                     market_id,
                     pool_shares_amount_out,
                     min_amounts_out,
                 }))
-                .map_err(Into::<Error>::into)
+                .map_err(Into::<ZeitgeistError>::into)
         }
 
         #[ink(message)]
         pub fn neoswap_withdraw_fees(&mut self, market_id: MarketId) -> Result<()> {
-            self.env()
+            self.runtime()
                 .call_runtime(&RuntimeCall::NeoSwaps(NeoSwapsCall::WithdrawFees {
                     market_id,
                 }))
-                .map_err(Into::<Error>::into)
+                .map_err(Into::<ZeitgeistError>::into)
         }
 
         #[ink(message)]
@@ -814,14 +941,14 @@ mod ztg_runtime_example {
             spot_prices: ink::prelude::vec::Vec<Balance>,
             swap_fee: Balance,
         ) -> Result<()> {
-            self.env()
+            self.runtime()
                 .call_runtime(&RuntimeCall::NeoSwaps(NeoSwapsCall::DeployPool {
                     market_id,
                     amount,
                     spot_prices,
                     swap_fee,
                 }))
-                .map_err(Into::<Error>::into)
+                .map_err(Into::<ZeitgeistError>::into)
         }
 
         // endregion
@@ -830,11 +957,11 @@ mod ztg_runtime_example {
 
         #[ink(message)]
         pub fn remove_order(&mut self, order_id: OrderId) -> Result<()> {
-            self.env()
+            self.runtime()
                 .call_runtime(&RuntimeCall::Orderbook(OrderbookCall::RemoveOrder {
                     order_id,
                 }))
-                .map_err(Into::<Error>::into)
+                .map_err(Into::<ZeitgeistError>::into)
         }
 
         #[ink(message)]
@@ -843,12 +970,12 @@ mod ztg_runtime_example {
             order_id: OrderId,
             maker_partial_fill: Option<Balance>,
         ) -> Result<()> {
-            self.env()
+            self.runtime()
                 .call_runtime(&RuntimeCall::Orderbook(OrderbookCall::FillOrder {
                     order_id,
                     maker_partial_fill,
                 }))
-                .map_err(Into::<Error>::into)
+                .map_err(Into::<ZeitgeistError>::into)
         }
 
         #[ink(message)]
@@ -860,7 +987,7 @@ mod ztg_runtime_example {
             taker_asset: ZeitgeistAsset,
             taker_amount: Balance,
         ) -> Result<()> {
-            self.env()
+            self.runtime()
                 .call_runtime(&RuntimeCall::Orderbook(OrderbookCall::PlaceOrder {
                     market_id,
                     maker_asset,
@@ -868,7 +995,7 @@ mod ztg_runtime_example {
                     taker_asset,
                     taker_amount,
                 }))
-                .map_err(Into::<Error>::into)
+                .map_err(Into::<ZeitgeistError>::into)
         }
 
         // endregion
@@ -877,32 +1004,279 @@ mod ztg_runtime_example {
 
         #[ink(message)]
         pub fn parimutuel_buy(&mut self, asset: ZeitgeistAsset, amount: Balance) -> Result<()> {
-            self.env()
+            self.runtime()
                 .call_runtime(&RuntimeCall::Parimutuel(ParimutelCall::Buy {
                     asset,
                     amount,
                 }))
-                .map_err(Into::<Error>::into)
+                .map_err(Into::<ZeitgeistError>::into)
         }
 
         #[ink(message)]
         pub fn parimutuel_claim_rewards(&mut self, market_id: MarketId) -> Result<()> {
-            self.env()
+            self.runtime()
                 .call_runtime(&RuntimeCall::Parimutuel(ParimutelCall::ClaimRewards {
                     market_id,
                 }))
-                .map_err(Into::<Error>::into)
+                .map_err(Into::<ZeitgeistError>::into)
         }
 
         #[ink(message)]
         pub fn parimutuel_claim_refunds(&mut self, refund_asset: ZeitgeistAsset) -> Result<()> {
-            self.env()
+            self.runtime()
                 .call_runtime(&RuntimeCall::Parimutuel(ParimutelCall::ClaimRefunds {
                     refund_asset,
                 }))
-                .map_err(Into::<Error>::into)
+                .map_err(Into::<ZeitgeistError>::into)
         }
 
         // endregion
     }
+
+    /// Runs the messages of the example contract against a mocked runtime, without a node.
+    #[cfg(test)]
+    mod tests {
+        use super::ZtgRuntimeExample;
+        use ink::primitives::AccountId;
+        use sp_runtime::Perbill;
+        use ztg_runtime_lib::{
+            assert_dispatched,
+            errors::{NeoSwapsError, PredictionMarketsError, ZeitgeistError},
+            harness::{RecordingDispatcher, RuntimeHarness},
+            lmsr::BASE,
+            primitives::*,
+            runtime_structs::*,
+        };
+
+        const MARKET_ID: MarketId = 3;
+        const YES: ZeitgeistAsset = ZeitgeistAsset::CategoricalOutcome(MARKET_ID, 0);
+        const NO: ZeitgeistAsset = ZeitgeistAsset::CategoricalOutcome(MARKET_ID, 1);
+
+        fn alice() -> AccountId {
+            ink::env::test::default_accounts::<ink::env::DefaultEnvironment>().alice
+        }
+
+        fn redeem_shares(market_id: MarketId) -> RuntimeCall {
+            RuntimeCall::PredictionMarkets(PredictionMarketsCall::RedeemShares { market_id })
+        }
+
+        #[ink::test]
+        fn captures_asset_manager_and_balances_calls() {
+            let runtime = RuntimeHarness::install();
+            let mut contract = ZtgRuntimeExample::default();
+
+            contract.transfer(alice(), 5 * BASE).unwrap();
+            contract.balances_transfer_keep_alive(alice(), BASE).unwrap();
+            assert_eq!(
+                runtime.calls(),
+                vec![
+                    RuntimeCall::AssetManager(AssetManagerCall::Transfer {
+                        dest: alice().into(),
+                        currency_id: ZeitgeistAsset::Ztg,
+                        amount: 5 * BASE,
+                    }),
+                    RuntimeCall::Balances(BalancesCall::TransferKeepAlive { dest: alice().into(), value: BASE }),
+                ]
+            );
+        }
+
+        #[ink::test]
+        fn captures_prediction_markets_and_authorized_calls() {
+            let runtime = RuntimeHarness::install();
+            let mut contract = ZtgRuntimeExample::default();
+
+            contract.report(MARKET_ID, OutcomeReport::Categorical(1)).unwrap();
+            contract.authorize_market_outcome(MARKET_ID, OutcomeReport::Categorical(0)).unwrap();
+            contract.redeem_shares(MARKET_ID).unwrap();
+            assert_eq!(
+                runtime.calls(),
+                vec![
+                    RuntimeCall::PredictionMarkets(PredictionMarketsCall::Report {
+                        market_id: MARKET_ID,
+                        outcome: OutcomeReport::Categorical(1),
+                    }),
+                    RuntimeCall::Authorized(AuthorizedCall::AuthorizeMarketOutcome {
+                        market_id: MARKET_ID,
+                        outcome: OutcomeReport::Categorical(0),
+                    }),
+                    redeem_shares(MARKET_ID),
+                ]
+            );
+        }
+
+        #[ink::test]
+        fn captures_court_and_global_disputes_calls() {
+            let runtime = RuntimeHarness::install();
+            let mut contract = ZtgRuntimeExample::default();
+
+            contract.join_court(500 * BASE).unwrap();
+            assert_eq!(
+                runtime.last_call(),
+                Some(RuntimeCall::Court(CourtCall::JoinCourt { amount: 500 * BASE }))
+            );
+            contract.vote(7, [1; 32]).unwrap();
+            assert_eq!(
+                runtime.last_call(),
+                Some(RuntimeCall::Court(CourtCall::Vote { court_id: 7, commitment_vote: [1; 32] }))
+            );
+            contract.vote_on_outcome(MARKET_ID, OutcomeReport::Scalar(20), BASE).unwrap();
+            assert_eq!(
+                runtime.last_call(),
+                Some(RuntimeCall::GlobalDisputes(GlobalDisputesCall::VoteOnOutcome {
+                    market_id: MARKET_ID,
+                    outcome: OutcomeReport::Scalar(20),
+                    amount: BASE,
+                }))
+            );
+            assert_eq!(runtime.calls().len(), 3);
+        }
+
+        #[ink::test]
+        fn captures_trading_calls() {
+            let runtime = RuntimeHarness::install();
+            let mut contract = ZtgRuntimeExample::default();
+
+            contract.neoswap_buy(MARKET_ID, 2, YES, 10 * BASE, 15 * BASE).unwrap();
+            contract.place_order(MARKET_ID, ZeitgeistAsset::Ztg, 3 * BASE, NO, 10 * BASE).unwrap();
+            contract.fill_order(4, None).unwrap();
+            contract.parimutuel_buy(YES, BASE).unwrap();
+            contract.cross().unwrap();
+            assert_eq!(
+                runtime.calls(),
+                vec![
+                    RuntimeCall::NeoSwaps(NeoSwapsCall::Buy {
+                        market_id: MARKET_ID,
+                        asset_count: 2,
+                        asset_out: YES,
+                        amount_in: 10 * BASE,
+                        min_amount_out: 15 * BASE,
+                    }),
+                    RuntimeCall::Orderbook(OrderbookCall::PlaceOrder {
+                        market_id: MARKET_ID,
+                        maker_asset: ZeitgeistAsset::Ztg,
+                        maker_amount: 3 * BASE,
+                        taker_asset: NO,
+                        taker_amount: 10 * BASE,
+                    }),
+                    RuntimeCall::Orderbook(OrderbookCall::FillOrder { order_id: 4, maker_partial_fill: None }),
+                    RuntimeCall::Parimutuel(ParimutelCall::Buy { asset: YES, amount: BASE }),
+                    RuntimeCall::Styx(StyxCall::Cross),
+                ]
+            );
+        }
+
+        #[ink::test]
+        fn captures_the_encoded_payload() {
+            let runtime = RuntimeHarness::install();
+            let mut contract = ZtgRuntimeExample::default();
+
+            contract.redeem_shares(MARKET_ID).unwrap();
+            assert_eq!(runtime.payloads(), vec![scale::Encode::encode(&redeem_shares(MARKET_ID))]);
+            runtime.clear();
+            assert!(runtime.calls().is_empty());
+        }
+
+        #[ink::test]
+        fn returns_scripted_failures() {
+            let runtime = RuntimeHarness::install();
+            let mut contract = ZtgRuntimeExample::default();
+
+            runtime.respond(Err(ZeitgeistError::CallRuntimeFailed));
+            assert_eq!(contract.redeem_shares(MARKET_ID), Err(ZeitgeistError::CallRuntimeFailed));
+            assert_eq!(contract.redeem_shares(MARKET_ID), Ok(()));
+            // Failed calls are captured as well.
+            assert_eq!(runtime.calls().len(), 2);
+        }
+
+        #[ink::test]
+        fn answers_calls_with_a_handler() {
+            let runtime = RuntimeHarness::install();
+            let mut contract = ZtgRuntimeExample::default();
+
+            let resolved = ZeitgeistError::PredictionMarkets(PredictionMarketsError::MarketIsNotResolved);
+            runtime.handler(move |call| match call {
+                RuntimeCall::PredictionMarkets(PredictionMarketsCall::RedeemShares { market_id: 1 }) => Ok(()),
+                RuntimeCall::PredictionMarkets(PredictionMarketsCall::RedeemShares { .. }) => Err(resolved),
+                _ => Ok(()),
+            });
+            assert_eq!(contract.redeem_shares(1), Ok(()));
+            assert_eq!(contract.redeem_shares(2), Err(resolved));
+
+            // Queued responses take precedence.
+            runtime.respond(Ok(()));
+            assert_eq!(contract.redeem_shares(2), Ok(()));
+            assert_eq!(contract.redeem_shares(2), Err(resolved));
+        }
+
+        #[ink::test]
+        fn stops_dispatching_queued_calls_at_the_first_failure() {
+            let runtime = RuntimeHarness::install();
+            let mut contract = ZtgRuntimeExample::default();
+
+            for market_id in 0..3 {
+                contract.queue_call(redeem_shares(market_id));
+            }
+            runtime.respond(Ok(()));
+            runtime.respond(Err(ZeitgeistError::CallRuntimeFailed));
+            assert_eq!(contract.dispatch_queued(), Err(ZeitgeistError::CallRuntimeFailed));
+            assert_eq!(runtime.calls(), vec![redeem_shares(0), redeem_shares(1)]);
+
+            // The queue is cleared even if a call failed.
+            runtime.clear();
+            assert_eq!(contract.dispatch_queued(), Ok(()));
+            assert!(runtime.calls().is_empty());
+        }
+
+        #[ink::test]
+        fn batches_calls() {
+            let runtime = RuntimeHarness::install();
+            let mut contract = ZtgRuntimeExample::default();
+
+            contract.utility_batch_all(vec![redeem_shares(0), redeem_shares(1)]).unwrap();
+            assert_eq!(
+                runtime.calls(),
+                vec![Batch::new().call(redeem_shares(0)).call(redeem_shares(1)).batch_all()]
+            );
+        }
+
+        #[ink::test]
+        fn does_not_dispatch_trades_beyond_the_slippage() {
+            let runtime = RuntimeHarness::install();
+            let mut contract = ZtgRuntimeExample::default();
+            let pool = NeoSwapsPool {
+                account_id: [9; 32].into(),
+                reserves: vec![(YES, 10 * BASE), (NO, 10 * BASE)],
+                collateral: ZeitgeistAsset::Ztg,
+                liquidity_parameter: 144_269_504_088,
+                swap_fee: 0,
+            };
+
+            assert_eq!(
+                contract.neoswap_buy_with_max_slippage(MARKET_ID, pool.clone(), YES, BASE, Perbill::zero(), 10_001),
+                Err(ZeitgeistError::NeoSwaps(NeoSwapsError::NumericalLimits))
+            );
+            assert!(runtime.calls().is_empty());
+
+            contract.neoswap_buy_with_max_slippage(MARKET_ID, pool, YES, BASE, Perbill::zero(), 100).unwrap();
+            let Some(RuntimeCall::NeoSwaps(NeoSwapsCall::Buy { amount_in, min_amount_out, .. })) =
+                runtime.last_call()
+            else {
+                panic!("not a buy: {:?}", runtime.last_call());
+            };
+            assert_eq!(amount_in, BASE);
+            assert!(min_amount_out > BASE);
+        }
+
+        #[ink::test]
+        fn records_queued_calls_with_a_dispatcher() {
+            let mut contract = ZtgRuntimeExample::default();
+            contract.queue_call(redeem_shares(MARKET_ID));
+            contract.queue_call(RuntimeCall::NeoSwaps(NeoSwapsCall::WithdrawFees { market_id: MARKET_ID }));
+
+            let mut dispatcher = RecordingDispatcher::new();
+            contract.dispatch_queued_with(&mut dispatcher).unwrap();
+            assert_dispatched!(dispatcher, RuntimeCall::NeoSwaps(NeoSwapsCall::WithdrawFees { market_id: MARKET_ID }));
+            assert_eq!(dispatcher.calls().len(), 2);
+        }
+    }
 }
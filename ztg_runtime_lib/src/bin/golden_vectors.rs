@@ -0,0 +1,36 @@
+//! Prints the golden vectors of `RuntimeCall` for a SCALE-encoded runtime metadata file.
+//!
+//! Usage: `ztg-golden-vectors METADATA_FILE > tests/fixtures/calls.txt`
+
+use std::process::ExitCode;
+use ztg_runtime_lib::{golden, metadata::RuntimeCalls};
+
+fn main() -> ExitCode {
+    let Some(path) = std::env::args().nth(1) else {
+        eprintln!("usage: ztg-golden-vectors METADATA_FILE");
+        return ExitCode::FAILURE;
+    };
+    let calls = match std::fs::read(&path)
+        .map_err(|e| e.to_string())
+        .and_then(|bytes| RuntimeCalls::decode(&bytes).map_err(|e| e.to_string()))
+    {
+        Ok(calls) => calls,
+        Err(e) => {
+            eprintln!("{path}: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match golden::generate(&calls) {
+        Ok(vectors) => {
+            let file_name = std::path::Path::new(&path).file_name().unwrap_or_default();
+            let source = format!("runtime metadata {}", file_name.to_string_lossy());
+            print!("{}", golden::to_fixtures(&vectors, &source));
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,23 @@
+//! Prints the pallet, call and arguments of a hex encoded `RuntimeCall`.
+//!
+//! Usage: `ztg-inspect-call <HEX_CALL_DATA>`
+
+use std::process::ExitCode;
+use ztg_runtime_lib::{inspect::inspect_hex, runtime_structs::RuntimeCall};
+
+fn main() -> ExitCode {
+    let Some(hex) = std::env::args().nth(1) else {
+        eprintln!("Usage: ztg-inspect-call <HEX_CALL_DATA>");
+        return ExitCode::FAILURE;
+    };
+    match inspect_hex(&hex) {
+        Ok(call) => {
+            println!("{call}");
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("Not a {} `RuntimeCall`: {e}", RuntimeCall::NETWORK);
+            ExitCode::FAILURE
+        }
+    }
+}
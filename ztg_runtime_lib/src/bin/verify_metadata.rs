@@ -0,0 +1,44 @@
+//! Verifies `RuntimeCall` and `ZeitgeistError` against a SCALE-encoded runtime metadata file.
+//!
+//! Usage: `ztg-verify-metadata <METADATA_FILE>`
+
+use std::process::ExitCode;
+use ztg_runtime_lib::{metadata::RuntimeCalls, runtime_structs::RuntimeCall};
+
+fn main() -> ExitCode {
+    let Some(path) = std::env::args().nth(1) else {
+        eprintln!("Usage: ztg-verify-metadata <METADATA_FILE>");
+        return ExitCode::FAILURE;
+    };
+    let calls = match std::fs::read(&path)
+        .map_err(|e| e.to_string())
+        .and_then(|bytes| RuntimeCalls::decode(&bytes).map_err(|e| e.to_string()))
+    {
+        Ok(calls) => calls,
+        Err(e) => {
+            eprintln!("{path}: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match &calls.spec {
+        Some((name, version)) => println!("{path}: {name} spec version {version}"),
+        None => println!("{path}: no System::Version constant"),
+    }
+
+    let mismatches: Vec<String> = calls
+        .verify()
+        .iter()
+        .map(ToString::to_string)
+        .chain(calls.verify_errors().iter().map(ToString::to_string))
+        .collect();
+    if mismatches.is_empty() {
+        println!("RuntimeCall and ZeitgeistError ({}) match {path}", RuntimeCall::NETWORK);
+        return ExitCode::SUCCESS;
+    }
+    for mismatch in &mismatches {
+        println!("{mismatch}");
+    }
+    println!("{} mismatches against {path}", mismatches.len());
+    ExitCode::FAILURE
+}
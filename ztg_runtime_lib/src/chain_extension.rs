@@ -0,0 +1,254 @@
+use ink::env::{DefaultEnvironment, Environment};
+use ink::primitives::AccountId;
+
+use crate::errors::ZeitgeistError;
+use crate::primitives::*;
+use crate::runtime_structs::RuntimeCall;
+
+/// Read-only queries into Zeitgeist's pallet storage.
+///
+/// The runtime must register a chain extension that implements these function ids. Every query
+/// returns `None` if the requested item does not exist.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```ignore
+/// # use ztg_runtime_lib::{primitives::*, runtime_structs::*};
+/// let status = self.env().extension().market_status(market_id);
+/// if status == Some(MarketStatus::Closed) {
+///     self.env().call_runtime(&RuntimeCall::PredictionMarkets(
+///         PredictionMarketsCall::Report { market_id, outcome },
+///     ))?;
+/// }
+/// ```
+#[ink::chain_extension]
+pub trait ZeitgeistExtension {
+    type ErrorCode = ZeitgeistExtensionError;
+
+    /* ===================== Market Commons ===================== */
+
+    /// Returns a market.
+    #[ink(extension = 0x0000, handle_status = false)]
+    fn market(market_id: MarketId) -> Option<Market>;
+
+    /// Returns the status of a market.
+    #[ink(extension = 0x0001, handle_status = false)]
+    fn market_status(market_id: MarketId) -> Option<MarketStatus>;
+
+    /// Returns the report of a market, if it has been reported.
+    #[ink(extension = 0x0002, handle_status = false)]
+    fn market_report(market_id: MarketId) -> Option<Report>;
+
+    /// Returns the resolved outcome of a market, if it has been resolved.
+    #[ink(extension = 0x0003, handle_status = false)]
+    fn market_resolved_outcome(market_id: MarketId) -> Option<OutcomeReport>;
+
+    /* ======================== NeoSwaps ======================== */
+
+    /// Returns the liquidity pool deployed for a market.
+    #[ink(extension = 0x0101, handle_status = false)]
+    fn pool(market_id: MarketId) -> Option<NeoSwapsPool>;
+
+    /* ======================== Orderbook ======================= */
+
+    /// Returns an open order.
+    #[ink(extension = 0x0201, handle_status = false)]
+    fn order(order_id: OrderId) -> Option<Order>;
+
+    /* ========================== Court ========================= */
+
+    /// Returns a court case.
+    #[ink(extension = 0x0301, handle_status = false)]
+    fn court(court_id: CourtId) -> Option<CourtInfo>;
+
+    /// Returns the court case that was opened for a market.
+    #[ink(extension = 0x0302, handle_status = false)]
+    fn court_id_of_market(market_id: MarketId) -> Option<CourtId>;
+
+    /// Returns the vote of a court participant drawn for a court case.
+    #[ink(extension = 0x0303, handle_status = false)]
+    fn court_vote(court_id: CourtId, court_participant: AccountId) -> Option<Vote>;
+
+    /* ======================== Dispatch ======================== */
+
+    /// Dispatches a call with the contract as origin, like `call_runtime`, but returns the
+    /// reason of a failure instead of `CallRuntimeFailed`. Returns `None` if the call succeeded.
+    ///
+    /// This is not a `Result`, because ink! only decodes the `Ok` value of a `Result` returned
+    /// by the runtime. Convert it with `.map_or(Ok(()), Err)`.
+    ///
+    /// Zeitgeist's runtime does not ship this function. The runtime's chain extension has to
+    /// decode the call as its `RuntimeCall`, charge its weight, dispatch it with the contract as
+    /// signed origin and write the encoding of `ZeitgeistError::from(dispatch_error)`. Without
+    /// it, calling the function traps the contract; use `call_runtime` instead.
+    #[ink(extension = 0x1000, handle_status = false)]
+    fn dispatch(call: RuntimeCall) -> Option<ZeitgeistError>;
+}
+
+/// The status code returned by the chain extension.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum ZeitgeistExtensionError {
+    /// The runtime does not know the requested function id.
+    UnknownFunction,
+    /// The runtime could not decode the input of the query.
+    InvalidInput,
+    /// The runtime returned a status code that is not known to this library.
+    Unknown(u32),
+}
+
+impl ink::env::chain_extension::FromStatusCode for ZeitgeistExtensionError {
+    fn from_status_code(status_code: u32) -> Result<(), Self> {
+        match status_code {
+            0 => Ok(()),
+            1 => Err(Self::UnknownFunction),
+            2 => Err(Self::InvalidInput),
+            code => Err(Self::Unknown(code)),
+        }
+    }
+}
+
+/// The ink! environment of Zeitgeist with the [`ZeitgeistExtension`] chain extension.
+///
+/// Use it with `#[ink::contract(env = ztg_runtime_lib::chain_extension::ZeitgeistEnvironment)]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum ZeitgeistEnvironment {}
+
+impl Environment for ZeitgeistEnvironment {
+    const MAX_EVENT_TOPICS: usize = <DefaultEnvironment as Environment>::MAX_EVENT_TOPICS;
+
+    type AccountId = <DefaultEnvironment as Environment>::AccountId;
+    type Balance = <DefaultEnvironment as Environment>::Balance;
+    type Hash = <DefaultEnvironment as Environment>::Hash;
+    // Zeitgeist uses 64 bit block numbers, unlike the default environment.
+    type BlockNumber = u64;
+    type Timestamp = <DefaultEnvironment as Environment>::Timestamp;
+
+    type ChainExtension = ZeitgeistExtension;
+}
+
+/// A mock of the [`ZeitgeistExtension`] for ink! off-chain unit tests.
+#[cfg(feature = "std")]
+pub mod mock {
+    use super::*;
+    use ink::env::test::ChainExtension;
+    use scale::{Decode, Encode};
+    use std::{cell::RefCell, collections::BTreeMap, rc::Rc};
+
+    /// The chain state served by [`MockZeitgeistExtension`].
+    #[derive(Default)]
+    pub struct MockState {
+        /// The markets, which also answer the status, report and resolved outcome queries.
+        pub markets: BTreeMap<MarketId, Market>,
+        pub pools: BTreeMap<MarketId, NeoSwapsPool>,
+        pub orders: BTreeMap<OrderId, Order>,
+        pub courts: BTreeMap<CourtId, CourtInfo>,
+        pub court_ids: BTreeMap<MarketId, CourtId>,
+        pub court_votes: BTreeMap<(CourtId, AccountId), Vote>,
+        /// The calls passed to `dispatch`, in order.
+        pub dispatched: Vec<RuntimeCall>,
+        /// The error returned by `dispatch`, if it should fail.
+        pub dispatch_error: Option<ZeitgeistError>,
+    }
+
+    /// Answers chain extension queries from an in-memory [`MockState`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```ignore
+    /// #[ink::test]
+    /// fn reports_closed_markets() {
+    ///     let extension = MockZeitgeistExtension::default();
+    ///     extension.state().markets.insert(0, Market { status: MarketStatus::Closed, ..market });
+    ///     extension.register();
+    ///     // ... call the contract
+    /// }
+    /// ```
+    #[derive(Clone, Default)]
+    pub struct MockZeitgeistExtension {
+        state: Rc<RefCell<MockState>>,
+    }
+
+    impl MockZeitgeistExtension {
+        /// The function ids implemented by the mock.
+        pub const FUNC_IDS: [u32; 10] =
+            [0x0000, 0x0001, 0x0002, 0x0003, 0x0101, 0x0201, 0x0301, 0x0302, 0x0303, 0x1000];
+
+        /// Gives mutable access to the mocked chain state. Changes are visible to every
+        /// registered function, also after registration.
+        pub fn state(&self) -> std::cell::RefMut<'_, MockState> {
+            self.state.borrow_mut()
+        }
+
+        /// Registers every function of the extension with the off-chain environment.
+        pub fn register(&self) {
+            for func_id in Self::FUNC_IDS {
+                ink::env::test::register_chain_extension(MockFunction {
+                    func_id,
+                    state: self.state.clone(),
+                });
+            }
+        }
+    }
+
+    struct MockFunction {
+        func_id: u32,
+        state: Rc<RefCell<MockState>>,
+    }
+
+    impl MockFunction {
+        fn query(&self, input: &[u8]) -> Result<Vec<u8>, scale::Error> {
+            let input = &mut &input[..];
+            if self.func_id == 0x1000 {
+                let mut state = self.state.borrow_mut();
+                state.dispatched.push(RuntimeCall::decode(input)?);
+                return Ok(state.dispatch_error.encode());
+            }
+            let state = self.state.borrow();
+            let output = match self.func_id {
+                0x0000 => state.markets.get(&MarketId::decode(input)?).encode(),
+                0x0001 => state.markets.get(&MarketId::decode(input)?).map(|m| m.status).encode(),
+                0x0002 => {
+                    state.markets.get(&MarketId::decode(input)?).and_then(|m| m.report.clone()).encode()
+                }
+                0x0003 => state
+                    .markets
+                    .get(&MarketId::decode(input)?)
+                    .and_then(|m| m.resolved_outcome.clone())
+                    .encode(),
+                0x0101 => state.pools.get(&MarketId::decode(input)?).encode(),
+                0x0201 => state.orders.get(&OrderId::decode(input)?).encode(),
+                0x0301 => state.courts.get(&CourtId::decode(input)?).encode(),
+                0x0302 => state.court_ids.get(&MarketId::decode(input)?).encode(),
+                0x0303 => state.court_votes.get(&<(CourtId, AccountId)>::decode(input)?).encode(),
+                _ => return Err("unknown function id".into()),
+            };
+            Ok(output)
+        }
+    }
+
+    impl ChainExtension for MockFunction {
+        fn func_id(&self) -> u32 {
+            self.func_id
+        }
+
+        fn call(&mut self, input: &[u8], output: &mut Vec<u8>) -> u32 {
+            // The off-chain engine hands over the encoded input as a byte vector.
+            let Ok(input) = Vec::<u8>::decode(&mut &input[..]) else {
+                return 2;
+            };
+            match self.query(&input) {
+                Ok(encoded) => {
+                    output.extend(encoded);
+                    0
+                }
+                Err(_) => 2,
+            }
+        }
+    }
+}
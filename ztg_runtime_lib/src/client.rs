@@ -0,0 +1,165 @@
+//! Signed extrinsics for off-chain services.
+//!
+//! Builds the same [`RuntimeCall`]s that contracts dispatch into signed, SCALE-encoded
+//! extrinsics, which can be submitted with the `author_submitExtrinsic` RPC of any node.
+//!
+//! The signed extensions follow the runtime's `SignedExtra`:
+//! https://github.com/zeitgeistpm/zeitgeist/blob/7ea631dbff5ea519a970c5bc0f3d3d143849d3b9/runtime/common/src/lib.rs#L95-L105
+
+use ink::primitives::AccountId;
+use scale::{Compact, Encode};
+use sp_core::{ed25519, sr25519, Pair};
+use sp_runtime::{generic::Era, MultiSignature};
+
+use crate::primitives::{ZeitgeistAsset, ZtgAddress};
+use crate::runtime_structs::{Balance, RuntimeCall};
+
+/// The version of the extrinsic format, with the signed bit set.
+const SIGNED_EXTRINSIC_V4: u8 = 0b1000_0000 | 4;
+
+/// Signs the payload of an extrinsic.
+pub trait Signer {
+    /// The account that signs and pays for the extrinsic.
+    fn account_id(&self) -> AccountId;
+
+    /// Signs the (possibly hashed) payload returned by [`signer_payload`].
+    fn sign(&self, payload: &[u8]) -> MultiSignature;
+}
+
+impl Signer for sr25519::Pair {
+    fn account_id(&self) -> AccountId {
+        AccountId::from(self.public().0)
+    }
+
+    fn sign(&self, payload: &[u8]) -> MultiSignature {
+        Pair::sign(self, payload).into()
+    }
+}
+
+impl Signer for ed25519::Pair {
+    fn account_id(&self) -> AccountId {
+        AccountId::from(self.public().0)
+    }
+
+    fn sign(&self, payload: &[u8]) -> MultiSignature {
+        Pair::sign(self, payload).into()
+    }
+}
+
+/// The values of the signed extensions of an extrinsic.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```ignore
+/// # use ztg_runtime_lib::{client::*, runtime_structs::*};
+/// let params = ExtrinsicParams::new(spec_version, transaction_version, genesis_hash)
+///     .nonce(account_nonce)
+///     // The era of 64 blocks starts at the best block itself.
+///     .mortal(64, best_block_number, best_block_hash);
+/// let pair = sp_core::sr25519::Pair::from_string("//Alice", None).unwrap();
+/// let extrinsic = sign(&call, &params, &pair);
+/// // submit `0x{hex(extrinsic)}` with `author_submitExtrinsic`
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExtrinsicParams {
+    pub era: Era,
+    /// The hash of the block the era starts at, or the genesis hash if the era is immortal.
+    pub era_checkpoint: [u8; 32],
+    pub nonce: u64,
+    pub tip: Balance,
+    /// The asset to pay fees in, `None` for ZTG.
+    pub fee_asset: Option<ZeitgeistAsset>,
+    pub spec_version: u32,
+    pub transaction_version: u32,
+    pub genesis_hash: [u8; 32],
+}
+
+impl ExtrinsicParams {
+    /// Creates the params of an immortal extrinsic with nonce 0 and no tip.
+    pub fn new(spec_version: u32, transaction_version: u32, genesis_hash: [u8; 32]) -> Self {
+        Self {
+            era: Era::Immortal,
+            era_checkpoint: genesis_hash,
+            nonce: 0,
+            tip: 0,
+            fee_asset: None,
+            spec_version,
+            transaction_version,
+            genesis_hash,
+        }
+    }
+
+    /// Sets the nonce of the signing account.
+    pub fn nonce(mut self, nonce: u64) -> Self {
+        self.nonce = nonce;
+        self
+    }
+
+    /// Sets the tip paid to the block author.
+    pub fn tip(mut self, tip: Balance) -> Self {
+        self.tip = tip;
+        self
+    }
+
+    /// Pays the fees in a foreign asset instead of ZTG.
+    pub fn fee_asset(mut self, asset: ZeitgeistAsset) -> Self {
+        self.fee_asset = Some(asset);
+        self
+    }
+
+    /// Makes the extrinsic valid for about `period` blocks from the block `block_number`, which
+    /// usually is the best block.
+    ///
+    /// `birth_hash` is the hash of the block the era starts at, [`era_birth`]. `Era::mortal`
+    /// rounds `period` up to a power of two, and for periods over 4096 moves the start back to a
+    /// multiple of `period / 4096`, so the start is only `block_number` itself for periods up to
+    /// 4096. Fetch the hash of any other start with the `chain_getBlockHash` RPC, otherwise the
+    /// runtime rejects the extrinsic with a bad proof.
+    pub fn mortal(mut self, period: u64, block_number: u64, birth_hash: [u8; 32]) -> Self {
+        self.era = Era::mortal(period, block_number);
+        self.era_checkpoint = birth_hash;
+        self
+    }
+
+    /// The signed extensions that are part of the extrinsic.
+    fn extra(&self) -> impl Encode + '_ {
+        // CheckNonZeroSender, CheckSpecVersion, CheckTxVersion, CheckGenesis and CheckWeight
+        // encode to nothing.
+        (self.era, Compact(self.nonce), Compact(self.tip), &self.fee_asset)
+    }
+
+    /// The data that is signed but not part of the extrinsic.
+    fn additional_signed(&self) -> impl Encode + '_ {
+        (self.spec_version, self.transaction_version, self.genesis_hash, self.era_checkpoint)
+    }
+}
+
+/// Returns the block a mortal era of `period` blocks from `block_number` starts at, whose hash
+/// [`ExtrinsicParams::mortal`] expects.
+pub fn era_birth(period: u64, block_number: u64) -> u64 {
+    Era::mortal(period, block_number).birth(block_number)
+}
+
+/// Returns the payload that is signed for an extrinsic. Payloads longer than 256 bytes are
+/// replaced by their blake2-256 hash.
+pub fn signer_payload(call: &RuntimeCall, params: &ExtrinsicParams) -> Vec<u8> {
+    let payload = (call, params.extra(), params.additional_signed()).encode();
+    if payload.len() > 256 {
+        sp_io::hashing::blake2_256(&payload).to_vec()
+    } else {
+        payload
+    }
+}
+
+/// Signs a call and returns the SCALE-encoded extrinsic, prefixed with its length.
+pub fn sign(call: &RuntimeCall, params: &ExtrinsicParams, signer: &impl Signer) -> Vec<u8> {
+    let signature = signer.sign(&signer_payload(call, params));
+    let address = ZtgAddress::from(signer.account_id());
+
+    let mut extrinsic = vec![SIGNED_EXTRINSIC_V4];
+    (address, signature, params.extra(), call).encode_to(&mut extrinsic);
+    // An extrinsic is encoded like a `Vec<u8>`.
+    extrinsic.encode()
+}
@@ -0,0 +1,83 @@
+use ink::env::hash::Blake2x256;
+use ink::primitives::AccountId;
+use scale::{Decode, Encode};
+#[cfg(feature = "std")]
+use ink::storage::traits::StorageLayout;
+
+use crate::primitives::*;
+use crate::runtime_structs::{CourtCall, RuntimeCall};
+
+/// Returns the commitment of a secret vote, as verified by zrml-court when the vote is revealed
+/// or denounced: `BlakeTwo256` of the encoded `(juror, vote_item, salt)`.
+/// https://github.com/zeitgeistpm/zeitgeist/tree/release-v0.5.0/zrml/court/src/lib.rs
+///
+/// Uses the hashing of the contract environment, so it works on-chain and off-chain.
+pub fn commitment(juror: &AccountId, vote_item: &VoteItem, salt: &CourtHash) -> CourtHash {
+    let mut output = CourtHash::default();
+    ink::env::hash_encoded::<Blake2x256, _>(&(juror, vote_item, salt), &mut output);
+    output
+}
+
+/// Derives the salt of a court case from a secret, so that a juror only has to keep one secret
+/// instead of a salt per vote. Every court case gets a different salt.
+pub fn derive_salt(secret: &[u8; 32], court_id: CourtId) -> CourtHash {
+    let mut output = CourtHash::default();
+    ink::env::hash_encoded::<Blake2x256, _>(&(b"zrml-court/salt", secret, court_id), &mut output);
+    output
+}
+
+/// A vote that is committed first and revealed later.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```ignore
+/// # use ztg_runtime_lib::{court::*, primitives::*};
+/// let vote = SecretVote::new(VoteItem::Outcome(OutcomeReport::Categorical(1)), &secret, court_id);
+/// self.env().call_runtime(&vote.vote(court_id, self.env().account_id()))?;
+/// // ... once the voting period is over
+/// self.env().call_runtime(&vote.reveal(court_id))?;
+/// ```
+#[derive(Clone, Debug, Decode, Encode, PartialEq)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, StorageLayout))]
+pub struct SecretVote {
+    pub vote_item: VoteItem,
+    pub salt: CourtHash,
+}
+
+impl SecretVote {
+    /// Creates a vote with the salt derived from `secret`, see [`derive_salt`].
+    pub fn new(vote_item: VoteItem, secret: &[u8; 32], court_id: CourtId) -> Self {
+        Self { vote_item, salt: derive_salt(secret, court_id) }
+    }
+
+    /// Returns the commitment of the vote for `juror`.
+    pub fn commitment(&self, juror: &AccountId) -> CourtHash {
+        commitment(juror, &self.vote_item, &self.salt)
+    }
+
+    /// Commits to the vote. Must be dispatched by `juror`.
+    pub fn vote(&self, court_id: CourtId, juror: &AccountId) -> RuntimeCall {
+        RuntimeCall::Court(CourtCall::Vote { court_id, commitment_vote: self.commitment(juror) })
+    }
+
+    /// Reveals the vote of the caller.
+    pub fn reveal(&self, court_id: CourtId) -> RuntimeCall {
+        RuntimeCall::Court(CourtCall::RevealVote {
+            court_id,
+            vote_item: self.vote_item.clone(),
+            salt: self.salt,
+        })
+    }
+
+    /// Denounces `juror`, whose vote became known before it was revealed.
+    pub fn denounce(&self, court_id: CourtId, juror: impl Into<ZtgAddress>) -> RuntimeCall {
+        RuntimeCall::Court(CourtCall::DenounceVote {
+            court_id,
+            juror: juror.into(),
+            vote_item: self.vote_item.clone(),
+            salt: self.salt,
+        })
+    }
+}
@@ -0,0 +1,592 @@
+use scale::{Decode, Encode};
+use sp_runtime::{DispatchError, ModuleError};
+
+use crate::network::{Network, Target};
+
+/// An error of a Zeitgeist runtime call, decoded from the runtime's `DispatchError`.
+///
+/// The errors of each pallet are declared in the same order as the pallet's `Error` enum, so
+/// that the index of a `DispatchError::Module` maps onto them. Use [`crate::metadata`] to check
+/// them against a runtime.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```ignore
+/// # use ztg_runtime_lib::{errors::ZeitgeistError, runtime_structs::*};
+/// #[ink(message)]
+/// pub fn redeem_shares(&mut self, market_id: MarketId) -> Result<(), ZeitgeistError> {
+///     self.env()
+///         .call_runtime(&RuntimeCall::PredictionMarkets(
+///             PredictionMarketsCall::RedeemShares { market_id },
+///         ))
+///         .map_err(Into::into)
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum ZeitgeistError {
+    AssetManager(AssetManagerError),
+    Authorized(AuthorizedError),
+    Court(CourtError),
+    Swaps(SwapsError),
+    PredictionMarkets(PredictionMarketsError),
+    Styx(StyxError),
+    GlobalDisputes(GlobalDisputesError),
+    NeoSwaps(NeoSwapsError),
+    Orderbook(OrderbookError),
+    Parimutuel(ParimutuelError),
+    Utility(UtilityError),
+    Balances(BalancesError),
+    /// An error of a pallet or variant that is not known to this library.
+    Module { index: u8, error: u8 },
+    /// The call failed with an error that does not originate from a pallet, for example
+    /// `BadOrigin` or an arithmetic error.
+    Dispatch,
+    /// The call failed, but `pallet-contracts` did not forward the reason. Query the reason with
+    /// [`crate::chain_extension::ZeitgeistExtension::dispatch`] instead.
+    CallRuntimeFailed,
+    /// Another error of the contract environment.
+    Environment,
+    /// The error could not be decoded.
+    Decode,
+}
+
+impl ZeitgeistError {
+    /// Maps the pallet and error index of a `DispatchError::Module` onto the pallet's error.
+    pub fn from_module_error(index: u8, error: [u8; 4]) -> Self {
+        fn decode<E: Decode>(error: [u8; 4]) -> Option<E> {
+            E::decode(&mut &error[..]).ok()
+        }
+
+        let known = match index {
+            i if i == <Target as Network>::ASSET_MANAGER => decode(error).map(Self::AssetManager),
+            i if i == <Target as Network>::AUTHORIZED => decode(error).map(Self::Authorized),
+            i if i == <Target as Network>::COURT => decode(error).map(Self::Court),
+            i if i == <Target as Network>::SWAPS => decode(error).map(Self::Swaps),
+            i if i == <Target as Network>::PREDICTION_MARKETS => {
+                decode(error).map(Self::PredictionMarkets)
+            }
+            i if i == <Target as Network>::STYX => decode(error).map(Self::Styx),
+            i if i == <Target as Network>::GLOBAL_DISPUTES => decode(error).map(Self::GlobalDisputes),
+            i if i == <Target as Network>::NEO_SWAPS => decode(error).map(Self::NeoSwaps),
+            i if i == <Target as Network>::ORDERBOOK => decode(error).map(Self::Orderbook),
+            i if i == <Target as Network>::PARIMUTUEL => decode(error).map(Self::Parimutuel),
+            i if i == <Target as Network>::UTILITY => decode(error).map(Self::Utility),
+            i if i == <Target as Network>::BALANCES => decode(error).map(Self::Balances),
+            _ => None,
+        };
+        known.unwrap_or(Self::Module { index, error: error[0] })
+    }
+}
+
+impl From<DispatchError> for ZeitgeistError {
+    fn from(e: DispatchError) -> Self {
+        match e {
+            DispatchError::Module(ModuleError { index, error, .. }) => {
+                Self::from_module_error(index, error)
+            }
+            _ => Self::Dispatch,
+        }
+    }
+}
+
+impl From<ink::env::Error> for ZeitgeistError {
+    fn from(e: ink::env::Error) -> Self {
+        match e {
+            ink::env::Error::CallRuntimeFailed => Self::CallRuntimeFailed,
+            ink::env::Error::Decode(_) => Self::Decode,
+            _ => Self::Environment,
+        }
+    }
+}
+
+impl From<scale::Error> for ZeitgeistError {
+    fn from(_: scale::Error) -> Self {
+        Self::Decode
+    }
+}
+
+/* ========================== Pallet Errors ========================== */
+
+/// Errors of orml-currencies.
+/// https://github.com/open-web3-stack/open-runtime-module-library/blob/22a4f7b7d1066c1a138222f4546d527d32aa4047/currencies/src/lib.rs#L107
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum AssetManagerError {
+    /// Unable to convert the Amount type into Balance.
+    AmountIntoBalanceFailed,
+    /// Balance is too low.
+    BalanceTooLow,
+    /// Deposit result is not expected.
+    DepositFailed,
+}
+
+/// Errors of zrml-authorized.
+/// https://github.com/zeitgeistpm/zeitgeist/tree/release-v0.5.0/zrml/authorized/src/lib.rs
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum AuthorizedError {
+    /// An unauthorized account attempts to submit a report.
+    NotAuthorizedForThisMarket,
+    /// The market unexpectedly has the incorrect dispute mechanism.
+    MarketDoesNotHaveDisputeMechanismAuthorized,
+    /// An account attempts to submit a report to an undisputed market.
+    MarketIsNotDisputed,
+    /// The report does not match the market's type.
+    OutcomeMismatch,
+    /// The market should be reported at this point.
+    MarketIsNotReported,
+    /// The authority already made its report.
+    AuthorityAlreadyReported,
+}
+
+/// Errors of zrml-court.
+/// https://github.com/zeitgeistpm/zeitgeist/tree/release-v0.5.0/zrml/court/src/lib.rs
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum CourtError {
+    /// An account id does not exist on the court participants list.
+    CallerIsNotACourtParticipant,
+    /// There are not enough jurors in the pool.
+    NotEnoughJurorsAndDelegatorsStake,
+    /// The report of the market was not found.
+    MarketReportNotFound,
+    /// The maximum number of court participants is reached.
+    MaxCourtParticipantsReached,
+    /// The court participant already prepared to exit the court.
+    AlreadyPreparedExit,
+    /// The juror needs to exit the court and then rejoin.
+    NeedToExitFirst,
+    /// The juror was not randomly selected for the court.
+    CallerNotInSelectedDraws,
+    /// The market does not have a court as its dispute mechanism.
+    MarketDoesNotHaveCourtMechanism,
+    /// This operation is only allowed in the voting period.
+    NotInVotingPeriod,
+    /// The vote is not secret.
+    VoteAlreadyRevealed,
+    /// The outcome and salt reveal do not match the secret vote.
+    InvalidReveal,
+    /// No court for this market id was found.
+    CourtNotFound,
+    /// This operation is only allowed in the aggregation period.
+    NotInAggregationPeriod,
+    /// The juror stake would be slashed below the required minimum.
+    BelowMinJurorStake,
+    /// The maximum number of appeals has been reached.
+    MaxAppealsReached,
+    /// This operation is only allowed in the appeal period.
+    NotInAppealPeriod,
+    /// The court is already present for this market.
+    CourtAlreadyExists,
+    /// The caller of this extrinsic needs to be drawn or in the commitment vote state.
+    InvalidVoteState,
+    /// The amount is below the minimum required stake.
+    AmountBelowLowerBound,
+    /// The amount exceeds the free balance of the account.
+    AmountExceedsBalance,
+    /// After the first join of the court the amount has to be higher than the current stake.
+    AmountBelowLastJoin,
+    /// The random number generation failed.
+    NotEnoughRandomNumbers,
+    /// The juror was already denounced.
+    VoteAlreadyDenounced,
+    /// A juror tried to denounce herself.
+    CallerDenouncedItself,
+    /// The court is not in the closed state.
+    CourtNotClosed,
+    /// The court stakes were already reassigned.
+    CourtAlreadyReassigned,
+    /// The juror has to wait a full inflation period before exiting.
+    PrematureExit,
+    /// The delegated juror is not an active juror.
+    DelegatedToInvalidJuror,
+    /// The juror was not drawn for the court case.
+    NotDrawnJuror,
+    /// The delegations are empty.
+    NoDelegations,
+    /// The same juror is delegated to twice.
+    IdenticalDelegationsNotAllowed,
+    /// A court participant tried to delegate to itself.
+    SelfDelegationNotAllowed,
+    /// The maximum number of delegations is reached.
+    MaxDelegationsReached,
+    /// The vote item is not of the expected type.
+    InvalidVoteItem,
+    /// The market is not disputed.
+    MarketIsNotDisputed,
+}
+
+/// Errors of zrml-swaps.
+/// https://github.com/zeitgeistpm/zeitgeist/tree/release-v0.5.0/zrml/swaps/src/lib.rs
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum SwapsError {
+    /// The weight of an asset in a pool is too high.
+    AboveMaximumWeight,
+    /// The asset in question could not be found within the pool.
+    AssetNotBound,
+    /// The asset in question is not in the pool.
+    AssetNotInPool,
+    /// The base asset was not found in the pool.
+    BaseAssetNotFound,
+    /// The spot price of an asset pair was greater than the specified limit.
+    BadLimitPrice,
+    /// The weight of an asset in a pool is too low.
+    BelowMinimumWeight,
+    /// Some funds could not be transferred due to a too low balance.
+    InsufficientBalance,
+    /// Liquidity provided to new pool is less than the minimum allowed balance.
+    InsufficientLiquidity,
+    /// The market was not started since the subsidy goal was not reached.
+    InsufficientSubsidy,
+    /// The swap fee argument is invalid.
+    InvalidFeeArgument,
+    /// An operation was executed on a pool in an invalid state.
+    InvalidStateTransition,
+    /// A weight argument is invalid.
+    InvalidWeightArgument,
+    /// A transferal of funds into a pool was above a threshold specified by the sender.
+    LimitIn,
+    /// A transferal of funds out of a pool was below a threshold specified by the receiver.
+    LimitOut,
+    /// The pool does not exist.
+    PoolDoesNotExist,
+    /// The pool is not active.
+    PoolIsNotActive,
+    /// The total weight of all assets within a pool is too high.
+    MaxTotalWeight,
+    /// Too many assets were provided to the pool.
+    TooManyAssets,
+    /// Too few assets were provided to the pool.
+    TooFewAssets,
+    /// The pool has no swap fee.
+    PoolMissingFee,
+    /// Tried to create a pool without the base asset.
+    BaseAssetNotInPool,
+    /// A math operation failed.
+    MathApproximation,
+    /// The trade is not supported by the pool.
+    UnsupportedTrade,
+    /// The amount is zero.
+    ZeroAmount,
+    /// The winning asset of the market is not in the pool.
+    WinningAssetNotFound,
+}
+
+/// Errors of zrml-prediction-markets.
+/// https://github.com/zeitgeistpm/zeitgeist/tree/release-v0.5.0/zrml/prediction-markets/src/lib.rs
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum PredictionMarketsError {
+    /// Only creator is able to edit the market.
+    EditorNotCreator,
+    /// EditReason's length greater than MaxEditReasonLen.
+    EditReasonLengthExceedsMaxEditReasonLen,
+    /// Market account does not have enough funds to pay out.
+    InsufficientFundsInMarketAccount,
+    /// Sender does not have enough share balance.
+    InsufficientShareBalance,
+    /// An invalid market type was found.
+    InvalidMarketType,
+    /// Sender does not have enough balance to buy shares.
+    NotEnoughBalance,
+    /// Market is already reported on.
+    MarketAlreadyReported,
+    /// The market duration is longer than allowed.
+    MarketDurationTooLong,
+    /// Market edit request is already in progress.
+    MarketEditRequestAlreadyInProgress,
+    /// Market is not requested for edit.
+    MarketEditNotRequested,
+    /// The market end is too late.
+    MarketEndTooLate,
+    /// The market end is in the past.
+    MarketEndTooSoon,
+    /// Market was expected to be active.
+    MarketIsNotActive,
+    /// Market was expected to be closed.
+    MarketIsNotClosed,
+    /// Market was expected to be disputed.
+    MarketIsNotDisputed,
+    /// Market was expected to be in proposed state.
+    MarketIsNotProposed,
+    /// Market was expected to be in reported state.
+    MarketIsNotReported,
+    /// Market was expected to be in resolved state.
+    MarketIsNotResolved,
+    /// The market start is too late.
+    MarketStartTooLate,
+    /// The market start is in the past.
+    MarketStartTooSoon,
+    /// The number of categories of a categorical market is too low.
+    NotEnoughCategories,
+    /// The user has no winning balance.
+    NoWinningBalance,
+    /// Submitter is not oracle.
+    ReporterNotOracle,
+    /// It was tried to append an item to storage beyond the boundaries.
+    StorageOverflow,
+    /// Too many categories for a categorical market.
+    TooManyCategories,
+    /// The dispute mechanism of the market is not supported.
+    InvalidDisputeMechanism,
+    /// The outcome being reported is not in the market's outcome range.
+    OutcomeMismatch,
+    /// RejectReason's length greater than MaxRejectReasonLen.
+    RejectReasonLengthExceedsMaxRejectReasonLen,
+    /// The scoring rule is not supported for this market type.
+    InvalidScoringRule,
+    /// The oracle duration is longer than allowed.
+    OracleDurationGreaterThanMaxOracleDuration,
+    /// The oracle duration is shorter than allowed.
+    OracleDurationSmallerThanMinOracleDuration,
+    /// The dispute duration is longer than allowed.
+    DisputeDurationGreaterThanMaxDisputeDuration,
+    /// The dispute duration is shorter than allowed.
+    DisputeDurationSmallerThanMinDisputeDuration,
+    /// The grace period is longer than allowed.
+    GracePeriodGreaterThanMaxGracePeriod,
+    /// The period of the market is not valid.
+    InvalidMarketPeriod,
+    /// The base asset is not allowed.
+    InvalidBaseAsset,
+    /// The market can not be closed early in its current state.
+    MarketNotEligibleForEarlyClose,
+    /// An early close was already scheduled.
+    EarlyCloseRequestAlreadyScheduled,
+    /// No early close is scheduled for the market.
+    NoEarlyCloseScheduled,
+    /// The early close is in a state that does not allow this operation.
+    InvalidEarlyCloseState,
+    /// The creator fee is higher than allowed.
+    FeeTooHigh,
+    /// The market is not trusted.
+    MarketIsNotTrusted,
+    /// The market can not be closed manually.
+    NotAllowedToCloseMarketManually,
+    /// The global dispute is already started.
+    GlobalDisputeExistsAlready,
+    /// The dispute mechanism of the market has not failed.
+    MarketDisputeMechanismNotFailed,
+    /// A trusted market requires a dispute duration of zero.
+    NonZeroDisputePeriodOnTrustedMarket,
+    /// The market does not allow global disputes.
+    InvalidDisputeMechanismForGlobalDispute,
+}
+
+/// Errors of zrml-styx.
+/// https://github.com/zeitgeistpm/zeitgeist/tree/release-v0.5.0/zrml/styx/src/lib.rs
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum StyxError {
+    /// Account has already crossed.
+    HasAlreadyCrossed,
+    /// Insufficient funds to pay the burn amount.
+    FundDoesNotHaveEnoughFreeBalance,
+}
+
+/// Errors of zrml-global-disputes.
+/// https://github.com/zeitgeistpm/zeitgeist/tree/release-v0.5.0/zrml/global-disputes/src/lib.rs
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum GlobalDisputesError {
+    /// Outcomes can no longer be added to the global dispute.
+    AddOutcomeEnded,
+    /// The market already has a global dispute.
+    GlobalDisputeAlreadyExists,
+    /// The global dispute of the market does not exist.
+    GlobalDisputeNotFound,
+    /// The global dispute is not active.
+    GlobalDisputeNotActive,
+    /// The global dispute is not finished.
+    GlobalDisputeNotFinished,
+    /// The amount is too low.
+    InsufficientAmount,
+    /// The maximum amount of owners is reached.
+    MaxOwnersReached,
+    /// The amount in the reward pot is zero.
+    NoFundsToReward,
+    /// No global dispute present at the moment.
+    NoGlobalDisputeInitialized,
+    /// There is no owner information for this outcome.
+    NoPossessionToReward,
+    /// The outcome does not exist.
+    OutcomeDoesNotExist,
+    /// The outcome has already been added.
+    OutcomeAlreadyExists,
+    /// The outcome does not match the market type.
+    OutcomeMismatch,
+    /// The outcomes were not purged yet.
+    OutcomesNotFullyCleaned,
+    /// Too many outcomes were added.
+    MaxOutcomesReached,
+    /// The global dispute has not been destroyed yet.
+    GlobalDisputeNotDestroyed,
+    /// The global dispute is not finished yet.
+    UnfinishedGlobalDispute,
+    /// Sender does not have enough funds for the vote on an outcome.
+    InsufficientBalance,
+    /// The number of locks of a voter is higher than allowed.
+    MaxVotesReached,
+}
+
+/// Errors of zrml-neo-swaps.
+/// https://github.com/zeitgeistpm/zeitgeist/tree/release-v0.5.0/zrml/neo-swaps/src/lib.rs
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum NeoSwapsError {
+    /// The number of assets in the pool is above the allowed maximum.
+    AssetCountAboveMax,
+    /// Amount paid is above the specified maximum.
+    AmountInAboveMax,
+    /// Amount received is below the specified minimum.
+    AmountOutBelowMin,
+    /// Specified asset was not found in this pool.
+    AssetNotFound,
+    /// Market already has an associated pool.
+    DuplicatePool,
+    /// Incorrect asset count.
+    IncorrectAssetCount,
+    /// An asset vector has the wrong length.
+    IncorrectVecLen,
+    /// User doesn't own enough pool shares.
+    InsufficientPoolShares,
+    /// The liquidity in the pool is too low.
+    LiquidityTooLow,
+    /// Sum of spot prices is not `1`.
+    InvalidSpotPrices,
+    /// Market's trading mechanism is not LMSR.
+    InvalidTradingMechanism,
+    /// Pool can only be traded on if the market is active.
+    MarketNotActive,
+    /// Deploying pools is only supported for scalar or binary/categorical markets.
+    MarketNotCategorical,
+    /// Some calculation failed.
+    MathError,
+    /// The user is not allowed to execute this command.
+    NotAllowed,
+    /// This feature is not yet implemented.
+    NotImplemented,
+    /// Some value in the operation is too large or small.
+    NumericalLimits,
+    /// Outstanding fees prevent liquidity withdrawal.
+    OutstandingFees,
+    /// Specified market does not have a pool.
+    PoolNotFound,
+    /// Spot price is above the allowed maximum.
+    SpotPriceAboveMax,
+    /// Spot price is below the allowed minimum.
+    SpotPriceBelowMin,
+    /// Swap fee is above the allowed maximum.
+    SwapFeeAboveMax,
+    /// Swap fee is below the allowed minimum.
+    SwapFeeBelowMin,
+    /// Unreachable code was reached.
+    Unexpected,
+    /// Specified monetary amount is zero.
+    ZeroAmount,
+}
+
+/// Errors of zrml-orderbook.
+/// https://github.com/zeitgeistpm/zeitgeist/tree/release-v0.5.0/zrml/orderbook/src/lib.rs
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum OrderbookError {
+    /// The sender is not the order creator.
+    NotOrderCreator,
+    /// The order does not exist.
+    OrderDoesNotExist,
+    /// The market is not active.
+    MarketIsNotActive,
+    /// The scoring rule is not orderbook.
+    InvalidScoringRule,
+    /// The specified outcome asset is not part of the market.
+    InvalidOutcomeAsset,
+    /// The maker partial fill leads to a too low quotient for the next order execution.
+    PartialFillNearFullFillNotAllowed,
+    /// The asset is not the market's base asset.
+    MarketBaseAssetNotPresent,
+    /// The amount is below the minimum balance.
+    BelowMinimumBalance,
+    /// The amount is too low.
+    AmountTooLow,
+}
+
+/// Errors of zrml-parimutuel.
+/// https://github.com/zeitgeistpm/zeitgeist/tree/release-v0.5.0/zrml/parimutuel/src/lib.rs
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum ParimutuelError {
+    /// There was no buyer for the winning outcome or all winners already claimed their rewards.
+    /// Use the `refund` extrinsic to get the initial bet back, in case there was no buyer for
+    /// the winning outcome.
+    NoRewardShareOutstanding,
+    /// The market is not active.
+    MarketIsNotActive,
+    /// The specified amount is below the minimum bet size.
+    AmountBelowMinimumBetSize,
+    /// The specified asset is not a parimutuel share.
+    NotParimutuelOutcome,
+    /// The specified asset was not found in the market assets.
+    InvalidOutcomeAsset,
+    /// The scoring rule is not parimutuel.
+    InvalidScoringRule,
+    /// The specified amount can not be transferred.
+    InsufficientBalance,
+    /// The market is not resolved yet.
+    MarketIsNotResolvedYet,
+    /// An unexpected error occured. This should never happen!
+    /// There was an internal coding mistake.
+    Unexpected,
+    /// There is no resolved outcome present for the market.
+    NoResolvedOutcome,
+    /// The refund is not allowed.
+    RefundNotAllowed,
+    /// There is no balance to refund.
+    RefundableBalanceIsZero,
+    /// There is no reward, because there are no winning shares.
+    NoWinningShares,
+    /// Only categorical markets are allowed for parimutuels.
+    NotCategorical,
+    /// There is no reward to distribute.
+    NoRewardToDistribute,
+}
+
+/// Errors of pallet_utility.
+/// https://github.com/paritytech/polkadot-sdk/blob/release-polkadot-v1.1.0/substrate/frame/utility/src/lib.rs
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum UtilityError {
+    /// Too many calls batched.
+    TooManyCalls,
+}
+
+/// Errors of pallet_balances.
+/// https://github.com/paritytech/polkadot-sdk/blob/release-polkadot-v1.1.0/substrate/frame/balances/src/lib.rs
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum BalancesError {
+    /// Vesting balance too high to send value.
+    VestingBalance,
+    /// Account liquidity restrictions prevent withdrawal.
+    LiquidityRestrictions,
+    /// Balance too low to send value.
+    InsufficientBalance,
+    /// Value too low to create account due to existential deposit.
+    ExistentialDeposit,
+    /// Transfer/payment would kill account.
+    Expendability,
+    /// A vesting schedule already exists for this account.
+    ExistingVestingSchedule,
+    /// Beneficiary account must pre-exist.
+    DeadAccount,
+    /// Number of named reserves exceed `MaxReserves`.
+    TooManyReserves,
+    /// Number of holds exceed `MaxHolds`.
+    TooManyHolds,
+    /// Number of freezes exceed `MaxFreezes`.
+    TooManyFreezes,
+}
@@ -0,0 +1,220 @@
+//! Golden vectors: one SCALE-encoded sample of every call of [`RuntimeCall`].
+//!
+//! The samples are generated from the call types of a runtime's metadata, so regenerating the
+//! fixtures of `tests/fixtures/calls.txt` against a new runtime turns any change of a pallet or
+//! call index, the field order or a compact encoding into a diff:
+//!
+//! ```sh
+//! cargo run --bin ztg-golden-vectors -- metadata/zeitgeist.scale > tests/fixtures/calls.txt
+//! ```
+//!
+//! The sample values only depend on the types, so the same calls of a runtime and of this
+//! library have the same golden vector:
+//! - Every number, and every compact number, is the next value of a counter that starts at 1
+//!   for each call. Booleans are `true`.
+//! - Arrays repeat one sample, e.g. an `AccountId` of the counter value `[n; 32]`.
+//! - Sequences have one element, except sequences of calls, which are empty.
+//! - Enums take their first variant with fields, e.g. `Some` of an `Option`, or their first
+//!   variant if none has fields.
+//!
+//! [`RuntimeCall`]: crate::runtime_structs::RuntimeCall
+
+use core::fmt;
+use scale::{Compact, Encode};
+use scale_info::{PortableRegistry, TypeDef, TypeDefPrimitive};
+
+use crate::metadata::{snake_case, variants, RuntimeCalls};
+
+/// The sample of a call, as it is encoded for `call_runtime`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GoldenVector {
+    pub pallet: String,
+    /// The name of the dispatchable, e.g. `buy_complete_set`.
+    pub call: String,
+    pub encoded: Vec<u8>,
+}
+
+impl GoldenVector {
+    /// The name of the call as it appears in the fixtures, e.g. `PredictionMarkets::report`.
+    pub fn name(&self) -> String {
+        format!("{}::{}", self.pallet, self.call)
+    }
+}
+
+/// The reasons a call of `RuntimeCall` can not be sampled from runtime metadata.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GoldenError {
+    /// The runtime has no pallet with calls of this name.
+    MissingPallet { pallet: String },
+    /// The pallet has no call of this name.
+    MissingCall { pallet: String, call: String },
+    /// An argument of the call has a type without sample, e.g. a string.
+    UnsupportedType { pallet: String, call: String, ty: u32 },
+}
+
+impl fmt::Display for GoldenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingPallet { pallet } => write!(f, "{pallet}: pallet not found in runtime"),
+            Self::MissingCall { pallet, call } => {
+                write!(f, "{pallet}::{call}: call not found in runtime")
+            }
+            Self::UnsupportedType { pallet, call, ty } => {
+                write!(f, "{pallet}::{call}: no sample for argument type {ty}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for GoldenError {}
+
+/// Samples every call of `RuntimeCall` with the indices and argument types of `runtime`.
+pub fn generate(runtime: &RuntimeCalls) -> Result<Vec<GoldenVector>, GoldenError> {
+    let (types, runtime_call) = RuntimeCalls::ours();
+    let mut vectors = Vec::new();
+
+    for pallet in variants(&types, runtime_call) {
+        let Some((_, index, call_ty)) = runtime.pallets.iter().find(|(n, ..)| *n == pallet.name)
+        else {
+            return Err(GoldenError::MissingPallet { pallet: pallet.name.clone() });
+        };
+        let runtime_calls = variants(&runtime.types, *call_ty);
+
+        for call in variants(&types, pallet.fields[0].ty.id) {
+            let name = snake_case(&call.name);
+            let Some(runtime_call) = runtime_calls.iter().find(|c| snake_case(&c.name) == name)
+            else {
+                return Err(GoldenError::MissingCall { pallet: pallet.name.clone(), call: name });
+            };
+
+            let mut sampler = Sampler { types: &runtime.types, next: 1 };
+            let mut encoded = vec![*index, runtime_call.index];
+            for field in &runtime_call.fields {
+                sampler.encode(field.ty.id, &mut encoded).map_err(|ty| {
+                    GoldenError::UnsupportedType {
+                        pallet: pallet.name.clone(),
+                        call: name.clone(),
+                        ty,
+                    }
+                })?;
+            }
+            vectors.push(GoldenVector { pallet: pallet.name.clone(), call: name, encoded });
+        }
+    }
+
+    Ok(vectors)
+}
+
+/// Formats golden vectors as the lines of a fixture file, `<pallet>::<call> <hex>`, with a
+/// header that records the `source` of the vectors, e.g. the metadata file.
+pub fn to_fixtures(vectors: &[GoldenVector], source: &str) -> String {
+    let mut fixtures = format!(
+        "# One SCALE-encoded sample of every `RuntimeCall`, see `ztg_runtime_lib::golden`.\n\
+         # Source: {source}.\n\
+         # Regenerate with `cargo run --bin ztg-golden-vectors -- <METADATA_FILE>`.\n",
+    );
+    for vector in vectors {
+        fixtures.push_str(&vector.name());
+        fixtures.push(' ');
+        for byte in &vector.encoded {
+            fixtures.push_str(&format!("{byte:02x}"));
+        }
+        fixtures.push('\n');
+    }
+    fixtures
+}
+
+struct Sampler<'a> {
+    types: &'a PortableRegistry,
+    next: u8,
+}
+
+impl Sampler<'_> {
+    fn next(&mut self) -> u8 {
+        let next = self.next;
+        self.next = self.next.wrapping_add(1);
+        next
+    }
+
+    /// Encodes a sample of the type `id`, or returns the id of a type without sample.
+    fn encode(&mut self, id: u32, out: &mut Vec<u8>) -> Result<(), u32> {
+        let ty = self.types.resolve(id).ok_or(id)?;
+        match &ty.type_def {
+            TypeDef::Composite(def) => {
+                for field in &def.fields {
+                    self.encode(field.ty.id, out)?;
+                }
+            }
+            TypeDef::Variant(def) => {
+                let variant = def
+                    .variants
+                    .iter()
+                    .find(|v| !v.fields.is_empty())
+                    .or(def.variants.first())
+                    .ok_or(id)?;
+                out.push(variant.index);
+                for field in &variant.fields {
+                    self.encode(field.ty.id, out)?;
+                }
+            }
+            TypeDef::Sequence(def) if self.is_call(def.type_param.id) => {
+                Compact(0u32).encode_to(out);
+            }
+            TypeDef::Sequence(def) => {
+                Compact(1u32).encode_to(out);
+                self.encode(def.type_param.id, out)?;
+            }
+            TypeDef::Array(def) => {
+                let mut element = Vec::new();
+                self.encode(def.type_param.id, &mut element)?;
+                for _ in 0..def.len {
+                    out.extend_from_slice(&element);
+                }
+            }
+            TypeDef::Tuple(def) => {
+                for field in &def.fields {
+                    self.encode(field.id, out)?;
+                }
+            }
+            TypeDef::Primitive(primitive) => self.encode_primitive(primitive, out).ok_or(id)?,
+            // The compact encoding of small numbers is the same for every width.
+            TypeDef::Compact(_) => Compact(u128::from(self.next())).encode_to(out),
+            TypeDef::BitSequence(_) => return Err(id),
+        }
+        Ok(())
+    }
+
+    fn encode_primitive(
+        &mut self,
+        primitive: &TypeDefPrimitive,
+        out: &mut Vec<u8>,
+    ) -> Option<()> {
+        let n = self.next();
+        match primitive {
+            TypeDefPrimitive::Bool => true.encode_to(out),
+            TypeDefPrimitive::U8 => n.encode_to(out),
+            TypeDefPrimitive::U16 => u16::from(n).encode_to(out),
+            TypeDefPrimitive::U32 => u32::from(n).encode_to(out),
+            TypeDefPrimitive::U64 => u64::from(n).encode_to(out),
+            TypeDefPrimitive::U128 => u128::from(n).encode_to(out),
+            TypeDefPrimitive::I8 => (n as i8).encode_to(out),
+            TypeDefPrimitive::I16 => i16::from(n).encode_to(out),
+            TypeDefPrimitive::I32 => i32::from(n).encode_to(out),
+            TypeDefPrimitive::I64 => i64::from(n).encode_to(out),
+            TypeDefPrimitive::I128 => i128::from(n).encode_to(out),
+            TypeDefPrimitive::Char
+            | TypeDefPrimitive::Str
+            | TypeDefPrimitive::U256
+            | TypeDefPrimitive::I256 => return None,
+        }
+        Some(())
+    }
+
+    /// Whether `id` is the call enum of a runtime, which batches contain.
+    fn is_call(&self, id: u32) -> bool {
+        self.types
+            .resolve(id)
+            .and_then(|ty| ty.path.segments.last())
+            .is_some_and(|name| name == "RuntimeCall")
+    }
+}
@@ -0,0 +1,345 @@
+//! Dispatching runtime calls, and a mocked runtime to test contracts without a node.
+//!
+//! The off-chain environment of ink! does not implement `call_runtime`, so contracts that
+//! dispatch through [`call_runtime`] instead of `self.env().call_runtime` can be tested with
+//! `cargo test`: while a `RuntimeHarness` is installed on the test's thread, every call is
+//! captured and answered by the harness. The harness only exists with the `testing` feature,
+//! which contracts enable for their dev-dependency on this crate. Without it, and always on
+//! chain, the call goes straight to `ink::env::call_runtime`.
+//!
+//! Contract logic that takes a [`ZeitgeistDispatcher`] can instead be tested with a
+//! [`RecordingDispatcher`], without any global state.
+
+use core::marker::PhantomData;
+use ink::env::{DefaultEnvironment, Environment};
+use ink::ChainExtensionInstance;
+#[cfg(feature = "std")]
+use std::collections::VecDeque;
+
+use crate::chain_extension::ZeitgeistExtension;
+use crate::errors::ZeitgeistError;
+use crate::runtime_structs::RuntimeCall;
+
+/// Dispatches runtime calls on behalf of a contract.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```ignore
+/// # use ztg_runtime_lib::{harness::*, runtime_structs::*};
+/// #[ink(message)]
+/// pub fn redeem(&mut self, market_id: MarketId) -> Result<(), ZeitgeistError> {
+///     self.redeem_with(&mut RuntimeDispatcher::<Environment>::new(), market_id)
+/// }
+///
+/// pub fn redeem_with(
+///     &mut self,
+///     dispatcher: &mut impl ZeitgeistDispatcher,
+///     market_id: MarketId,
+/// ) -> Result<(), ZeitgeistError> {
+///     dispatcher.dispatch(&RuntimeCall::PredictionMarkets(
+///         PredictionMarketsCall::RedeemShares { market_id },
+///     ))
+/// }
+///
+/// #[ink::test]
+/// fn redeems_shares() {
+///     let mut dispatcher = RecordingDispatcher::new();
+///     Contract::new().redeem_with(&mut dispatcher, 3).unwrap();
+///     assert_dispatched!(
+///         dispatcher,
+///         RuntimeCall::PredictionMarkets(PredictionMarketsCall::RedeemShares { market_id: 3 })
+///     );
+/// }
+/// ```
+pub trait ZeitgeistDispatcher {
+    /// Dispatches `call` with the contract as origin.
+    fn dispatch(&mut self, call: &RuntimeCall) -> Result<(), ZeitgeistError>;
+}
+
+impl<D: ZeitgeistDispatcher + ?Sized> ZeitgeistDispatcher for &mut D {
+    fn dispatch(&mut self, call: &RuntimeCall) -> Result<(), ZeitgeistError> {
+        (**self).dispatch(call)
+    }
+}
+
+/// Dispatches with `call_runtime` of the environment `E`, through [`call_runtime`]. Every
+/// failure is reported as [`ZeitgeistError::CallRuntimeFailed`].
+pub struct RuntimeDispatcher<E = DefaultEnvironment> {
+    _env: PhantomData<E>,
+}
+
+impl<E> RuntimeDispatcher<E> {
+    pub fn new() -> Self {
+        Self { _env: PhantomData }
+    }
+}
+
+// Not derived, which would require `E: Default`.
+impl<E> Default for RuntimeDispatcher<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E: Environment> RuntimeDispatcher<E> {
+    /// Dispatches `call` like `self.env().call_runtime`, so that a message can switch to the
+    /// dispatcher without changing its call.
+    pub fn call_runtime(&self, call: &RuntimeCall) -> Result<(), ZeitgeistError> {
+        call_runtime::<E>(call)
+    }
+}
+
+impl<E: Environment> ZeitgeistDispatcher for RuntimeDispatcher<E> {
+    fn dispatch(&mut self, call: &RuntimeCall) -> Result<(), ZeitgeistError> {
+        call_runtime::<E>(call)
+    }
+}
+
+/// Dispatches with the `dispatch` function of the [`ZeitgeistExtension`], which reports the
+/// reason of a failure. The runtime must register the chain extension.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ExtensionDispatcher;
+
+impl ZeitgeistDispatcher for ExtensionDispatcher {
+    fn dispatch(&mut self, call: &RuntimeCall) -> Result<(), ZeitgeistError> {
+        <ZeitgeistExtension as ChainExtensionInstance>::instantiate()
+            .dispatch(call.clone())
+            .map_or(Ok(()), Err)
+    }
+}
+
+/// Records the dispatched calls instead of dispatching them, for unit tests of contract logic.
+///
+/// Calls succeed unless a response was queued with [`Self::respond`].
+#[cfg(feature = "std")]
+#[derive(Clone, Debug, Default)]
+pub struct RecordingDispatcher {
+    calls: Vec<RuntimeCall>,
+    responses: VecDeque<Result<(), ZeitgeistError>>,
+}
+
+#[cfg(feature = "std")]
+impl RecordingDispatcher {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The dispatched calls, in order.
+    pub fn calls(&self) -> &[RuntimeCall] {
+        &self.calls
+    }
+
+    /// The last dispatched call.
+    pub fn last_call(&self) -> Option<&RuntimeCall> {
+        self.calls.last()
+    }
+
+    /// Forgets the recorded calls.
+    pub fn clear(&mut self) {
+        self.calls.clear();
+    }
+
+    /// Queues the response to the next call that has no response queued yet.
+    pub fn respond(&mut self, response: Result<(), ZeitgeistError>) {
+        self.responses.push_back(response);
+    }
+}
+
+#[cfg(feature = "std")]
+impl ZeitgeistDispatcher for RecordingDispatcher {
+    fn dispatch(&mut self, call: &RuntimeCall) -> Result<(), ZeitgeistError> {
+        self.calls.push(call.clone());
+        self.responses.pop_front().unwrap_or(Ok(()))
+    }
+}
+
+/// Asserts that a [`RecordingDispatcher`] or `RuntimeHarness` captured a call that matches a
+/// pattern, e.g. `RuntimeCall::NeoSwaps(NeoSwapsCall::Buy { market_id: 3, .. })`.
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! assert_dispatched {
+    ($recorder:expr, $pattern:pat $(if $guard:expr)? $(,)?) => {{
+        let calls = $recorder.calls();
+        assert!(
+            calls.iter().any(|call| matches!(call, $pattern $(if $guard)?)),
+            "no call matching `{}` was dispatched, calls: {:#?}",
+            stringify!($pattern),
+            calls,
+        );
+    }};
+}
+
+/// Dispatches `call` with the contract as origin.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```ignore
+/// # use ztg_runtime_lib::{harness::call_runtime, runtime_structs::*};
+/// call_runtime::<Environment>(&RuntimeCall::PredictionMarkets(
+///     PredictionMarketsCall::RedeemShares { market_id },
+/// ))?;
+/// ```
+pub fn call_runtime<E: Environment>(call: &RuntimeCall) -> Result<(), ZeitgeistError> {
+    #[cfg(feature = "testing")]
+    if let Some(result) = mock::dispatch(call) {
+        return result;
+    }
+    ink::env::call_runtime::<E, _>(call).map_err(Into::into)
+}
+
+#[cfg(feature = "testing")]
+pub use mock::RuntimeHarness;
+
+#[cfg(feature = "testing")]
+mod mock {
+    use scale::{Decode, Encode};
+    use std::cell::RefCell;
+    use std::collections::VecDeque;
+
+    use crate::errors::ZeitgeistError;
+    use crate::runtime_structs::RuntimeCall;
+
+    type Handler = Box<dyn FnMut(&RuntimeCall) -> Result<(), ZeitgeistError>>;
+
+    #[derive(Default)]
+    struct State {
+        payloads: Vec<Vec<u8>>,
+        responses: VecDeque<Result<(), ZeitgeistError>>,
+        handler: Option<Handler>,
+    }
+
+    thread_local! {
+        static STATE: RefCell<Option<State>> = RefCell::new(None);
+    }
+
+    /// Captures the payload of `call` and returns the scripted response, or `None` if no
+    /// harness is installed.
+    pub(super) fn dispatch(call: &RuntimeCall) -> Option<Result<(), ZeitgeistError>> {
+        // The handler runs without the state borrowed, so that it may dispatch calls itself.
+        let (queued, mut handler) = STATE.with(|state| {
+            let mut state = state.borrow_mut();
+            let state = state.as_mut()?;
+            state.payloads.push(call.encode());
+            Some((state.responses.pop_front(), state.handler.take()))
+        })?;
+        let result = match (queued, handler.as_mut()) {
+            (Some(response), _) => response,
+            (None, Some(handler)) => handler(call),
+            (None, None) => Ok(()),
+        };
+        STATE.with(|state| {
+            if let Some(state) = state.borrow_mut().as_mut() {
+                state.handler = state.handler.take().or(handler);
+            }
+        });
+        Some(result)
+    }
+
+    /// A mocked runtime that captures the calls dispatched with [`super::call_runtime`] on the
+    /// current thread, and answers them with scripted responses.
+    ///
+    /// Calls succeed unless a response was queued with [`Self::respond`] or a handler was set
+    /// with [`Self::handler`]. Queued responses are used first, in order. Note that on chain
+    /// every failed call is reported as [`ZeitgeistError::CallRuntimeFailed`], whatever the
+    /// dispatch error was.
+    ///
+    /// The harness is uninstalled when it is dropped.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```ignore
+    /// # use ztg_runtime_lib::{errors::ZeitgeistError, harness::RuntimeHarness, runtime_structs::*};
+    /// #[ink::test]
+    /// fn redeems_shares() {
+    ///     let runtime = RuntimeHarness::install();
+    ///     let mut contract = Contract::new();
+    ///     contract.redeem_shares(3).unwrap();
+    ///     assert_eq!(
+    ///         runtime.calls(),
+    ///         vec![RuntimeCall::PredictionMarkets(PredictionMarketsCall::RedeemShares {
+    ///             market_id: 3
+    ///         })]
+    ///     );
+    ///
+    ///     runtime.respond(Err(ZeitgeistError::CallRuntimeFailed));
+    ///     assert_eq!(contract.redeem_shares(3), Err(ZeitgeistError::CallRuntimeFailed));
+    /// }
+    /// ```
+    pub struct RuntimeHarness {
+        // Bound to the thread it is installed on.
+        _thread: core::marker::PhantomData<*const ()>,
+    }
+
+    impl RuntimeHarness {
+        /// Installs an empty harness on the current thread.
+        ///
+        /// # Panics
+        ///
+        /// If a harness is already installed on the current thread.
+        pub fn install() -> Self {
+            STATE.with(|state| {
+                let mut state = state.borrow_mut();
+                assert!(state.is_none(), "a harness is already installed on this thread");
+                *state = Some(State::default());
+            });
+            Self { _thread: core::marker::PhantomData }
+        }
+
+        fn with<R>(&self, f: impl FnOnce(&mut State) -> R) -> R {
+            STATE.with(|state| {
+                f(state.borrow_mut().as_mut().expect("the harness is installed until dropped; qed"))
+            })
+        }
+
+        /// The SCALE-encoded calls in the order they were dispatched, as passed to the runtime.
+        pub fn payloads(&self) -> Vec<Vec<u8>> {
+            self.with(|state| state.payloads.clone())
+        }
+
+        /// The calls in the order they were dispatched, decoded from their payloads.
+        pub fn calls(&self) -> Vec<RuntimeCall> {
+            self.payloads()
+                .iter()
+                .map(|payload| {
+                    RuntimeCall::decode(&mut &payload[..])
+                        .expect("payloads are encoded calls; qed")
+                })
+                .collect()
+        }
+
+        /// The last dispatched call.
+        pub fn last_call(&self) -> Option<RuntimeCall> {
+            self.calls().pop()
+        }
+
+        /// Forgets the captured calls.
+        pub fn clear(&self) {
+            self.with(|state| state.payloads.clear());
+        }
+
+        /// Queues the response to the next call that has no response queued yet.
+        pub fn respond(&self, response: Result<(), ZeitgeistError>) {
+            self.with(|state| state.responses.push_back(response));
+        }
+
+        /// Answers every call that has no queued response with `handler`.
+        pub fn handler(
+            &self,
+            handler: impl FnMut(&RuntimeCall) -> Result<(), ZeitgeistError> + 'static,
+        ) {
+            self.with(|state| state.handler = Some(Box::new(handler)));
+        }
+    }
+
+    impl Drop for RuntimeHarness {
+        fn drop(&mut self) {
+            STATE.with(|state| *state.borrow_mut() = None);
+        }
+    }
+}
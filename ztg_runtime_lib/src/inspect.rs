@@ -0,0 +1,319 @@
+//! Human readable inspection of encoded [`RuntimeCall`]s.
+//!
+//! The call is decoded with [`RuntimeCall`]'s `Decode` implementation and then described with
+//! the names of its type information, like the decode view of polkadot.js apps.
+//!
+//! ```sh
+//! cargo run --bin ztg-inspect-call -- 0x380981040402286bee00b102000000000000000000000000000001000100cdbe7b00000000000000000000000000
+//! ```
+
+use core::fmt;
+use scale::{Compact, Decode, DecodeLimit, Input};
+use scale_info::{form::PortableForm, Field, PortableRegistry, TypeDef, TypeDefPrimitive};
+
+use ink::primitives::AccountId;
+
+use crate::metadata::{snake_case, variants, RuntimeCalls};
+use crate::runtime_structs::{Balance, RuntimeCall};
+use crate::ss58;
+
+/// The maximum nesting depth of batched calls. The runtime allows deeper nesting, but decoding
+/// it could overflow the stack and no contract has a reason to dispatch it.
+const MAX_CALL_DEPTH: u32 = 32;
+
+/// The number of decimals of ZTG and of every other Zeitgeist asset.
+pub const ZTG_DECIMALS: u32 = 10;
+
+/// A decoded value of a call argument.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Value {
+    Bool(bool),
+    Char(char),
+    Str(String),
+    Uint(u128),
+    Int(i128),
+    /// A balance, printed in ZTG decimals.
+    Amount(Balance),
+    /// An account, printed as an SS58 address of the target network.
+    Account(AccountId),
+    /// A sequence or array of bytes, printed as hex.
+    Bytes(Vec<u8>),
+    Sequence(Vec<Value>),
+    /// A struct or tuple. Tuples have no field names.
+    Composite(Vec<(Option<String>, Value)>),
+    Variant(String, Vec<(Option<String>, Value)>),
+}
+
+/// A decoded [`RuntimeCall`] with the names of its pallet, call and arguments.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CallInfo {
+    pub pallet: String,
+    /// The name of the dispatchable, e.g. `buy_complete_set`.
+    pub call: String,
+    pub args: Vec<(String, Value)>,
+}
+
+/// Decodes an encoded [`RuntimeCall`]. Fails if the bytes are not exactly one call.
+pub fn inspect(bytes: &[u8]) -> Result<CallInfo, scale::Error> {
+    // Fails on trailing bytes, and on batches nested too deep to be dispatched.
+    RuntimeCall::decode_all_with_depth_limit(MAX_CALL_DEPTH, &mut &bytes[..])?;
+
+    let (types, runtime_call) = RuntimeCalls::ours();
+    let input = &mut &bytes[..];
+    let pallet = variant_of(&types, runtime_call, input.read_byte()?)?;
+    let call = variant_of(&types, pallet.fields[0].ty.id, input.read_byte()?)?;
+    let args = call
+        .fields
+        .iter()
+        .map(|field| {
+            let value = decode_field(&types, field, input)?;
+            Ok((field.name.clone().unwrap_or_default(), value))
+        })
+        .collect::<Result<_, scale::Error>>()?;
+
+    Ok(CallInfo { pallet: pallet.name.clone(), call: snake_case(&call.name), args })
+}
+
+/// Decodes a hex encoded [`RuntimeCall`], with or without `0x` prefix.
+pub fn inspect_hex(hex: &str) -> Result<CallInfo, scale::Error> {
+    let hex = hex.trim();
+    let hex = hex.strip_prefix("0x").unwrap_or(hex);
+    if hex.len() % 2 != 0 {
+        return Err("Hex call data has an odd length".into());
+    }
+    let bytes = (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2).unwrap_or_default(), 16))
+        .collect::<Result<Vec<u8>, _>>()
+        .map_err(|_| "Call data is not hex")?;
+    inspect(&bytes)
+}
+
+/// Formats an amount in ZTG decimals, e.g. `15_000_000_000` as `1.5`.
+pub fn format_amount(amount: Balance) -> String {
+    let unit = 10u128.pow(ZTG_DECIMALS);
+    let fraction = format!("{:01$}", amount % unit, ZTG_DECIMALS as usize);
+    let fraction = fraction.trim_end_matches('0');
+    if fraction.is_empty() {
+        format!("{}", amount / unit)
+    } else {
+        format!("{}.{fraction}", amount / unit)
+    }
+}
+
+fn variant_of(
+    types: &PortableRegistry,
+    id: u32,
+    index: u8,
+) -> Result<&scale_info::Variant<PortableForm>, scale::Error> {
+    variants(types, id)
+        .iter()
+        .find(|v| v.index == index)
+        .ok_or_else(|| "Unknown variant index".into())
+}
+
+/// Whether a field holds balances, judged by its declared type.
+fn is_amount(field: &Field<PortableForm>) -> bool {
+    let Some(type_name) = field.type_name.as_deref() else {
+        return false;
+    };
+    let inner = ["Vec<", "Option<"]
+        .iter()
+        .find_map(|wrapper| type_name.strip_prefix(wrapper)?.strip_suffix('>'))
+        .unwrap_or(type_name);
+    matches!(inner, "Balance" | "BalanceOf<T>")
+}
+
+/// Turns the integers of a balance, or of a sequence or option of balances, into amounts.
+fn into_amount(value: Value) -> Value {
+    match value {
+        Value::Uint(amount) => Value::Amount(amount),
+        Value::Sequence(values) => Value::Sequence(values.into_iter().map(into_amount).collect()),
+        Value::Variant(name, fields) => Value::Variant(
+            name,
+            fields.into_iter().map(|(name, value)| (name, into_amount(value))).collect(),
+        ),
+        other => other,
+    }
+}
+
+fn decode_field(
+    types: &PortableRegistry,
+    field: &Field<PortableForm>,
+    input: &mut &[u8],
+) -> Result<Value, scale::Error> {
+    let value = decode_value(types, field.ty.id, input)?;
+    Ok(if is_amount(field) { into_amount(value) } else { value })
+}
+
+fn decode_fields(
+    types: &PortableRegistry,
+    fields: &[Field<PortableForm>],
+    input: &mut &[u8],
+) -> Result<Vec<(Option<String>, Value)>, scale::Error> {
+    fields
+        .iter()
+        .map(|field| Ok((field.name.clone(), decode_field(types, field, input)?)))
+        .collect()
+}
+
+fn decode_value(
+    types: &PortableRegistry,
+    id: u32,
+    input: &mut &[u8],
+) -> Result<Value, scale::Error> {
+    let ty = types.resolve(id).ok_or("Unknown type id")?;
+    let is_byte = |id: u32| {
+        matches!(
+            types.resolve(id).map(|ty| &ty.type_def),
+            Some(TypeDef::Primitive(TypeDefPrimitive::U8))
+        )
+    };
+
+    Ok(match &ty.type_def {
+        // `AccountId` of ink! and `AccountId32` of sp-core, as runtime metadata names it.
+        TypeDef::Composite(_)
+            if ty
+                .path
+                .segments
+                .last()
+                .is_some_and(|name| name == "AccountId" || name == "AccountId32") =>
+        {
+            Value::Account(AccountId::decode(input)?)
+        }
+        TypeDef::Composite(def) => Value::Composite(decode_fields(types, &def.fields, input)?),
+        TypeDef::Variant(def) => {
+            let index = input.read_byte()?;
+            let variant = def
+                .variants
+                .iter()
+                .find(|v| v.index == index)
+                .ok_or("Unknown variant index")?;
+            Value::Variant(variant.name.clone(), decode_fields(types, &variant.fields, input)?)
+        }
+        TypeDef::Sequence(def) => {
+            let len = Compact::<u32>::decode(input)?.0 as usize;
+            if is_byte(def.type_param.id) {
+                let mut bytes = vec![0; len];
+                input.read(&mut bytes)?;
+                Value::Bytes(bytes)
+            } else {
+                Value::Sequence(
+                    (0..len)
+                        .map(|_| decode_value(types, def.type_param.id, input))
+                        .collect::<Result<_, _>>()?,
+                )
+            }
+        }
+        TypeDef::Array(def) => {
+            if is_byte(def.type_param.id) {
+                let mut bytes = vec![0; def.len as usize];
+                input.read(&mut bytes)?;
+                Value::Bytes(bytes)
+            } else {
+                Value::Sequence(
+                    (0..def.len)
+                        .map(|_| decode_value(types, def.type_param.id, input))
+                        .collect::<Result<_, _>>()?,
+                )
+            }
+        }
+        TypeDef::Tuple(def) => Value::Composite(
+            def.fields
+                .iter()
+                .map(|ty| Ok((None, decode_value(types, ty.id, input)?)))
+                .collect::<Result<_, scale::Error>>()?,
+        ),
+        TypeDef::Primitive(primitive) => decode_primitive(primitive, input)?,
+        TypeDef::Compact(_) => Value::Uint(Compact::<u128>::decode(input)?.0),
+        TypeDef::BitSequence(_) => return Err("Bit sequences are not supported".into()),
+    })
+}
+
+fn decode_primitive(primitive: &TypeDefPrimitive, input: &mut &[u8]) -> Result<Value, scale::Error> {
+    Ok(match primitive {
+        TypeDefPrimitive::Bool => Value::Bool(bool::decode(input)?),
+        TypeDefPrimitive::Char => {
+            Value::Char(char::from_u32(u32::decode(input)?).ok_or("Invalid char")?)
+        }
+        TypeDefPrimitive::Str => Value::Str(String::decode(input)?),
+        TypeDefPrimitive::U8 => Value::Uint(u8::decode(input)?.into()),
+        TypeDefPrimitive::U16 => Value::Uint(u16::decode(input)?.into()),
+        TypeDefPrimitive::U32 => Value::Uint(u32::decode(input)?.into()),
+        TypeDefPrimitive::U64 => Value::Uint(u64::decode(input)?.into()),
+        TypeDefPrimitive::U128 => Value::Uint(u128::decode(input)?),
+        TypeDefPrimitive::I8 => Value::Int(i8::decode(input)?.into()),
+        TypeDefPrimitive::I16 => Value::Int(i16::decode(input)?.into()),
+        TypeDefPrimitive::I32 => Value::Int(i32::decode(input)?.into()),
+        TypeDefPrimitive::I64 => Value::Int(i64::decode(input)?.into()),
+        TypeDefPrimitive::I128 => Value::Int(i128::decode(input)?),
+        TypeDefPrimitive::U256 => Value::Bytes(<[u8; 32]>::decode(input)?.to_vec()),
+        TypeDefPrimitive::I256 => Value::Bytes(<[u8; 32]>::decode(input)?.to_vec()),
+    })
+}
+
+fn fmt_fields(f: &mut fmt::Formatter<'_>, fields: &[(Option<String>, Value)]) -> fmt::Result {
+    let named = fields.iter().any(|(name, _)| name.is_some());
+    f.write_str(if named { "{ " } else { "(" })?;
+    for (i, (name, value)) in fields.iter().enumerate() {
+        if i != 0 {
+            f.write_str(", ")?;
+        }
+        if let Some(name) = name {
+            write!(f, "{name}: ")?;
+        }
+        write!(f, "{value}")?;
+    }
+    f.write_str(if named { " }" } else { ")" })
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bool(b) => write!(f, "{b}"),
+            Self::Char(c) => write!(f, "{c:?}"),
+            Self::Str(s) => write!(f, "{s:?}"),
+            Self::Uint(n) => write!(f, "{n}"),
+            Self::Int(n) => write!(f, "{n}"),
+            Self::Amount(amount) => f.write_str(&format_amount(*amount)),
+            Self::Account(account_id) => f.write_str(&ss58::to_ss58(account_id)),
+            Self::Bytes(bytes) => {
+                f.write_str("0x")?;
+                bytes.iter().try_for_each(|b| write!(f, "{b:02x}"))
+            }
+            Self::Sequence(values) => {
+                f.write_str("[")?;
+                for (i, value) in values.iter().enumerate() {
+                    if i != 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{value}")?;
+                }
+                f.write_str("]")
+            }
+            // Newtypes like `Perbill` are printed as their inner value.
+            Self::Composite(fields) if fields.len() == 1 && fields[0].0.is_none() => {
+                write!(f, "{}", fields[0].1)
+            }
+            Self::Composite(fields) => fmt_fields(f, fields),
+            Self::Variant(name, fields) if fields.is_empty() => f.write_str(name),
+            Self::Variant(name, fields) => {
+                f.write_str(name)?;
+                if fields.iter().any(|(name, _)| name.is_some()) {
+                    f.write_str(" ")?;
+                }
+                fmt_fields(f, fields)
+            }
+        }
+    }
+}
+
+impl fmt::Display for CallInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.pallet, self.call)?;
+        for (name, value) in &self.args {
+            write!(f, "\n  {name}: {value}")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,226 @@
+use ink::prelude::vec::Vec;
+use ink::primitives::AccountId;
+use scale::{Decode, Encode};
+#[cfg(feature = "std")]
+use ink::storage::traits::StorageLayout;
+
+use crate::court::SecretVote;
+use crate::primitives::*;
+use crate::runtime_structs::{Balance, CourtCall, RuntimeCall};
+
+/// Whether a contract takes part in the court.
+#[derive(Clone, Copy, Debug, Decode, Encode, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, StorageLayout))]
+pub enum Participation {
+    /// The contract has no stake in the court.
+    NotJoined,
+    /// The contract has joined and can be drawn for court cases.
+    Joined,
+    /// The contract prepared to exit and can exit once `unlock_at` is reached.
+    Exiting { unlock_at: u64 },
+}
+
+/// The progress of a court case the contract was drawn for.
+#[derive(Clone, Debug, Decode, Encode, PartialEq)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, StorageLayout))]
+pub enum CaseVote {
+    Drawn,
+    Committed(SecretVote),
+    Revealed(SecretVote),
+}
+
+/// The errors of [`JurorAgent`], returned when a call is made out of order.
+#[derive(Clone, Copy, Debug, Decode, Encode, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum JurorAgentError {
+    /// The contract has not joined the court.
+    NotJoined,
+    /// The contract prepared to exit the court.
+    Exiting,
+    /// A stake has to be higher than the previous one.
+    StakeNotIncreased,
+    /// The contract was not drawn for the court case.
+    NotDrawn,
+    /// The contract was already drawn for the court case.
+    AlreadyDrawn,
+    /// The contract already committed to a vote in the court case.
+    AlreadyCommitted,
+    /// The contract has not committed to a vote in the court case.
+    NotCommitted,
+    /// The exit is locked until the given block.
+    ExitLocked { unlock_at: u64 },
+    /// The contract is still drawn for court cases.
+    CasesPending,
+    /// The contract joined the court, but has not prepared to exit it.
+    NotExiting,
+}
+
+/// Tracks the court participation of a contract that acts as a juror, and builds the court
+/// calls in the order zrml-court accepts them.
+///
+/// Every method checks the state first and only changes it if the call can be made. The
+/// returned call still has to be dispatched, and the state should be reverted if that fails,
+/// which ink! does when the message returns an error.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```ignore
+/// # use ztg_runtime_lib::{court::SecretVote, juror::*};
+/// #[ink(storage)]
+/// pub struct Juror {
+///     agent: JurorAgent,
+/// }
+///
+/// #[ink(message)]
+/// pub fn vote(&mut self, court_id: CourtId, vote: SecretVote) -> Result<(), Error> {
+///     let call = self.agent.vote(court_id, vote, &self.env().account_id())?;
+///     self.env().call_runtime(&call)?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug, Decode, Encode, PartialEq)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, StorageLayout))]
+pub struct JurorAgent {
+    /// The number of blocks the court locks a stake after preparing to exit, which is the
+    /// runtime's `InflationPeriod`.
+    exit_delay: u64,
+    participation: Participation,
+    stake: Balance,
+    cases: Vec<(CourtId, CaseVote)>,
+}
+
+impl JurorAgent {
+    pub fn new(exit_delay: u64) -> Self {
+        Self { exit_delay, participation: Participation::NotJoined, stake: 0, cases: Vec::new() }
+    }
+
+    pub fn participation(&self) -> Participation {
+        self.participation
+    }
+
+    pub fn stake(&self) -> Balance {
+        self.stake
+    }
+
+    /// Returns the progress of a court case, or `None` if the contract was not drawn for it.
+    pub fn case(&self, court_id: CourtId) -> Option<&CaseVote> {
+        self.cases.iter().find(|(id, _)| *id == court_id).map(|(_, vote)| vote)
+    }
+
+    /// The court cases the contract was drawn for and that were not closed yet.
+    pub fn cases(&self) -> &[(CourtId, CaseVote)] {
+        &self.cases
+    }
+
+    fn case_mut(&mut self, court_id: CourtId) -> Result<&mut CaseVote, JurorAgentError> {
+        self.cases
+            .iter_mut()
+            .find(|(id, _)| *id == court_id)
+            .map(|(_, vote)| vote)
+            .ok_or(JurorAgentError::NotDrawn)
+    }
+
+    /// Joins the court with a stake of `amount`, or raises the stake if already joined.
+    pub fn join(&mut self, amount: Balance) -> Result<RuntimeCall, JurorAgentError> {
+        if let Participation::Exiting { .. } = self.participation {
+            return Err(JurorAgentError::Exiting);
+        }
+        if amount <= self.stake {
+            return Err(JurorAgentError::StakeNotIncreased);
+        }
+        self.participation = Participation::Joined;
+        self.stake = amount;
+        Ok(RuntimeCall::Court(CourtCall::JoinCourt { amount }))
+    }
+
+    /// Records that the contract was drawn for a court case, e.g. after querying
+    /// [`crate::chain_extension::ZeitgeistExtension::court_vote`].
+    pub fn drawn(&mut self, court_id: CourtId) -> Result<(), JurorAgentError> {
+        if self.participation == Participation::NotJoined {
+            return Err(JurorAgentError::NotJoined);
+        }
+        if self.case(court_id).is_some() {
+            return Err(JurorAgentError::AlreadyDrawn);
+        }
+        self.cases.push((court_id, CaseVote::Drawn));
+        Ok(())
+    }
+
+    /// Commits to a vote in a court case the contract was drawn for.
+    pub fn vote(
+        &mut self,
+        court_id: CourtId,
+        vote: SecretVote,
+        juror: &AccountId,
+    ) -> Result<RuntimeCall, JurorAgentError> {
+        let case = self.case_mut(court_id)?;
+        if *case != CaseVote::Drawn {
+            return Err(JurorAgentError::AlreadyCommitted);
+        }
+        let call = vote.vote(court_id, juror);
+        *case = CaseVote::Committed(vote);
+        Ok(call)
+    }
+
+    /// Reveals the vote the contract committed to.
+    pub fn reveal(&mut self, court_id: CourtId) -> Result<RuntimeCall, JurorAgentError> {
+        let case = self.case_mut(court_id)?;
+        let CaseVote::Committed(vote) = case else {
+            return Err(JurorAgentError::NotCommitted);
+        };
+        let vote = vote.clone();
+        let call = vote.reveal(court_id);
+        *case = CaseVote::Revealed(vote);
+        Ok(call)
+    }
+
+    /// Appeals the decision of a court case. Anyone can appeal, so this does not change the
+    /// state.
+    pub fn appeal(&self, court_id: CourtId) -> RuntimeCall {
+        RuntimeCall::Court(CourtCall::Appeal { court_id })
+    }
+
+    /// Forgets a court case once it is closed and its stakes were reassigned.
+    pub fn close(&mut self, court_id: CourtId) -> Result<(), JurorAgentError> {
+        self.case_mut(court_id)?;
+        self.cases.retain(|(id, _)| *id != court_id);
+        Ok(())
+    }
+
+    /// Prepares to exit the court at block `now`. The contract is no longer drawn afterwards.
+    pub fn prepare_exit(&mut self, now: u64) -> Result<RuntimeCall, JurorAgentError> {
+        match self.participation {
+            Participation::NotJoined => Err(JurorAgentError::NotJoined),
+            Participation::Exiting { .. } => Err(JurorAgentError::Exiting),
+            Participation::Joined => {
+                self.participation =
+                    Participation::Exiting { unlock_at: now.saturating_add(self.exit_delay) };
+                Ok(RuntimeCall::Court(CourtCall::PrepareExitCourt))
+            }
+        }
+    }
+
+    /// Exits the court at block `now` and unlocks the stake of `juror`, which is the contract.
+    pub fn exit(
+        &mut self,
+        now: u64,
+        juror: impl Into<ZtgAddress>,
+    ) -> Result<RuntimeCall, JurorAgentError> {
+        let unlock_at = match self.participation {
+            Participation::NotJoined => return Err(JurorAgentError::NotJoined),
+            Participation::Joined => return Err(JurorAgentError::NotExiting),
+            Participation::Exiting { unlock_at } => unlock_at,
+        };
+        if now < unlock_at {
+            return Err(JurorAgentError::ExitLocked { unlock_at });
+        }
+        if !self.cases.is_empty() {
+            return Err(JurorAgentError::CasesPending);
+        }
+        self.participation = Participation::NotJoined;
+        self.stake = 0;
+        Ok(RuntimeCall::Court(CourtCall::ExitCourt { court_participant: juror.into() }))
+    }
+}
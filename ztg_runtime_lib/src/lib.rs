@@ -2,3 +2,26 @@
 
 pub mod runtime_structs;
 pub mod primitives;
+pub mod chain_extension;
+pub mod network;
+pub mod errors;
+pub mod court;
+pub mod juror;
+pub mod market;
+pub mod orderbook;
+pub mod lmsr;
+pub mod neo_swaps;
+pub mod parimutuel;
+pub mod harness;
+#[cfg(feature = "std")]
+pub mod client;
+#[cfg(feature = "std")]
+pub mod metadata;
+#[cfg(feature = "std")]
+pub mod inspect;
+#[cfg(feature = "std")]
+pub mod golden;
+#[cfg(feature = "std")]
+pub mod market_metadata;
+#[cfg(feature = "std")]
+pub mod ss58;
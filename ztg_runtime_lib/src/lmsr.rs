@@ -0,0 +1,230 @@
+//! Fixed-point LMSR math of zrml-neo-swaps, for estimating trades on-chain.
+//! https://github.com/zeitgeistpm/zeitgeist/tree/release-v0.5.0/zrml/neo-swaps/src/math.rs
+//!
+//! Balances, prices and liquidity parameters use the 10 decimals of Zeitgeist's balances. The
+//! math is done with 18 decimals internally and results are rounded in favor of the pool.
+//!
+//! The pallet's own fixed-point rounding is not ported. Results are within [`max_deviation`] of
+//! the exact values and of the pallet's test vectors, but may still differ from the pallet's
+//! result by that much in either direction. [`crate::neo_swaps`] lowers `min_amount_out` by the
+//! deviation, so that a trade quoted with these functions does not fail on rounding alone.
+
+use ink::prelude::vec::Vec;
+use sp_runtime::helpers_128bit::multiply_by_rational_with_rounding;
+use sp_runtime::Rounding;
+
+use crate::errors::NeoSwapsError;
+use crate::runtime_structs::Balance;
+
+/// One unit of a balance.
+pub const BASE: Balance = 10_000_000_000;
+
+/// One unit of the internal fixed-point numbers.
+const ONE: u128 = 1_000_000_000_000_000_000;
+/// The factor between the internal precision and the precision of balances.
+const SCALE: u128 = ONE / BASE;
+/// ln(2) with 18 decimals.
+const LN_2: u128 = 693_147_180_559_945_309;
+/// The number of series terms used by `exp` and `ln`, enough for 18 decimals.
+const SERIES_TERMS: u128 = 40;
+
+/// Returns the maximum deviation of a result `amount` from the exact value: 10 units or one part
+/// in 10^10 of `amount`, whichever is larger.
+pub fn max_deviation(amount: Balance) -> Balance {
+    (amount / 10_000_000_000).max(10)
+}
+
+/// Returns the amount of `asset_out` the pool pays out for buying with `amount_in` collateral
+/// (after fees), where `reserve` is the pool's reserve of `asset_out`:
+/// `y(x) = b ln(e^(x/b) − 1 + e^(−r/b)) + r − x`.
+///
+/// The buyer receives `y(x) + x`, because `amount_in` first buys complete sets.
+pub fn calculate_swap_amount_out_for_buy(
+    reserve: Balance,
+    amount_in: Balance,
+    liquidity: Balance,
+) -> Result<Balance, NeoSwapsError> {
+    let (r, x, b) = (to_fixed(reserve)?, to_fixed(amount_in)?, to_fixed(liquidity)?);
+    let inner = exp(div(x, b)?)?
+        .checked_add(exp_neg(div(r, b)?)?)
+        .and_then(|sum| sum.checked_sub(ONE))
+        .ok_or(NeoSwapsError::NumericalLimits)?;
+    let (negative, ln_inner) = ln(inner)?;
+    // Rounds `y` down: a negative logarithm is subtracted, so its magnitude is rounded up.
+    let y = if negative {
+        r.checked_sub(mul_up(b, ln_inner)?)
+    } else {
+        r.checked_add(mul(b, ln_inner)?)
+    };
+    // The pool never pays out less than nothing, but the rounding could make it look so.
+    from_fixed(y.ok_or(NeoSwapsError::MathError)?.saturating_sub(x))
+}
+
+/// Returns the amount of collateral (before fees) received for selling `amount_in` of an asset
+/// with reserve `reserve`: `y(x) = −b ln(e^(−(x+r)/b) − e^(−r/b) + 1)`.
+pub fn calculate_swap_amount_out_for_sell(
+    reserve: Balance,
+    amount_in: Balance,
+    liquidity: Balance,
+) -> Result<Balance, NeoSwapsError> {
+    let (r, x, b) = (to_fixed(reserve)?, to_fixed(amount_in)?, to_fixed(liquidity)?);
+    let x_plus_r = x.checked_add(r).ok_or(NeoSwapsError::NumericalLimits)?;
+    let inner = (ONE - exp_neg(div(r, b)?)?)
+        .checked_add(exp_neg(div(x_plus_r, b)?)?)
+        .ok_or(NeoSwapsError::MathError)?;
+    // `inner` is at most one, so its logarithm is not positive.
+    let (_, ln_inner) = ln(inner)?;
+    from_fixed(mul(b, ln_inner)?)
+}
+
+/// Returns the spot price of an asset with reserve `reserve`: `p = e^(−r/b)`.
+pub fn calculate_spot_price(reserve: Balance, liquidity: Balance) -> Result<Balance, NeoSwapsError> {
+    from_fixed(exp_neg(div(to_fixed(reserve)?, to_fixed(liquidity)?)?)?)
+}
+
+/// Returns the reserve of an asset with spot price `spot_price`: `r = −b ln(p)`.
+pub fn calculate_reserve_from_spot_price(
+    liquidity: Balance,
+    spot_price: Balance,
+) -> Result<Balance, NeoSwapsError> {
+    let (_, ln_p) = ln_of_price(spot_price)?;
+    from_fixed(mul(to_fixed(liquidity)?, ln_p)?)
+}
+
+/// Returns the liquidity parameter for which an asset with reserve `reserve` has spot price
+/// `spot_price`: `b = −r / ln(p)`.
+pub fn calculate_liquidity_parameter(
+    reserve: Balance,
+    spot_price: Balance,
+) -> Result<Balance, NeoSwapsError> {
+    let (_, ln_p) = ln_of_price(spot_price)?;
+    if ln_p == 0 {
+        return Err(NeoSwapsError::InvalidSpotPrices);
+    }
+    from_fixed(div(to_fixed(reserve)?, ln_p)?)
+}
+
+/// Returns the reserves and the liquidity parameter of a pool deployed with `amount` complete
+/// sets at `spot_prices`. The asset with the lowest price keeps all `amount` units.
+pub fn calculate_reserves_from_spot_prices(
+    amount: Balance,
+    spot_prices: &[Balance],
+) -> Result<(Vec<Balance>, Balance), NeoSwapsError> {
+    let min_price = spot_prices.iter().min().ok_or(NeoSwapsError::IncorrectVecLen)?;
+    let liquidity = calculate_liquidity_parameter(amount, *min_price)?;
+    let reserves = spot_prices
+        .iter()
+        .map(|p| {
+            if p == min_price {
+                // Avoids the rounding error of the liquidity parameter.
+                Ok(amount)
+            } else {
+                calculate_reserve_from_spot_price(liquidity, *p).map(|r| r.min(amount))
+            }
+        })
+        .collect::<Result<_, _>>()?;
+    Ok((reserves, liquidity))
+}
+
+fn to_fixed(balance: Balance) -> Result<u128, NeoSwapsError> {
+    balance.checked_mul(SCALE).ok_or(NeoSwapsError::NumericalLimits)
+}
+
+fn from_fixed(value: u128) -> Result<Balance, NeoSwapsError> {
+    Ok(value / SCALE)
+}
+
+fn mul(a: u128, b: u128) -> Result<u128, NeoSwapsError> {
+    multiply_by_rational_with_rounding(a, b, ONE, Rounding::Down).ok_or(NeoSwapsError::NumericalLimits)
+}
+
+fn mul_up(a: u128, b: u128) -> Result<u128, NeoSwapsError> {
+    multiply_by_rational_with_rounding(a, b, ONE, Rounding::Up).ok_or(NeoSwapsError::NumericalLimits)
+}
+
+fn div(a: u128, b: u128) -> Result<u128, NeoSwapsError> {
+    if b == 0 {
+        return Err(NeoSwapsError::ZeroAmount);
+    }
+    multiply_by_rational_with_rounding(a, ONE, b, Rounding::Down).ok_or(NeoSwapsError::NumericalLimits)
+}
+
+/// Splits `x = k ln(2) + rest` with `rest < ln(2)`.
+fn reduce(x: u128) -> (u32, u128) {
+    let k = x / LN_2;
+    // Shifting by 128 or more overflows anyway, so larger values are clamped.
+    (k.min(u32::MAX as u128) as u32, x % LN_2)
+}
+
+/// e^x for `rest < ln(2)`, with the Taylor series.
+fn exp_reduced(rest: u128) -> Result<u128, NeoSwapsError> {
+    let (mut sum, mut term) = (ONE, ONE);
+    for n in 1..SERIES_TERMS {
+        term = mul(term, rest)? / n;
+        if term == 0 {
+            break;
+        }
+        sum += term;
+    }
+    Ok(sum)
+}
+
+/// e^x.
+fn exp(x: u128) -> Result<u128, NeoSwapsError> {
+    let (k, rest) = reduce(x);
+    let power = 1u128.checked_shl(k).filter(|_| k < 127).ok_or(NeoSwapsError::NumericalLimits)?;
+    exp_reduced(rest)?.checked_mul(power).ok_or(NeoSwapsError::NumericalLimits)
+}
+
+/// e^(−x), rounded down.
+fn exp_neg(x: u128) -> Result<u128, NeoSwapsError> {
+    let (k, rest) = reduce(x);
+    Ok(div(ONE, exp_reduced(rest)?)?.checked_shr(k).unwrap_or(0))
+}
+
+/// ln(y) for `y > 0`, as sign (`true` if negative) and magnitude.
+fn ln(y: u128) -> Result<(bool, u128), NeoSwapsError> {
+    if y == 0 {
+        return Err(NeoSwapsError::NumericalLimits);
+    }
+    // Normalize `y = m 2^k` with `1 <= m < 2`.
+    let (mut m, mut k, mut negative) = (y, 0u128, false);
+    while m >= 2 * ONE {
+        m /= 2;
+        k += 1;
+    }
+    while m < ONE {
+        m *= 2;
+        k += 1;
+        negative = true;
+    }
+
+    // ln(m) = 2 atanh(s) with s = (m − 1) / (m + 1) <= 1/3.
+    let s = div(m - ONE, m + ONE)?;
+    let s_squared = mul(s, s)?;
+    let (mut ln_m, mut power) = (0, s);
+    for n in 0..SERIES_TERMS {
+        if power == 0 {
+            break;
+        }
+        ln_m += power / (2 * n + 1);
+        power = mul(power, s_squared)?;
+    }
+    let ln_m = 2 * ln_m;
+
+    let k_ln_2 = k * LN_2;
+    Ok(if negative {
+        // `m 2^(−k)` is below one, and ln(m) < ln(2) <= k ln(2).
+        (true, k_ln_2 - ln_m)
+    } else {
+        (false, k_ln_2 + ln_m)
+    })
+}
+
+/// ln(p) of a spot price `0 < p <= 1`, which is not positive.
+fn ln_of_price(spot_price: Balance) -> Result<(bool, u128), NeoSwapsError> {
+    if spot_price == 0 || spot_price > BASE {
+        return Err(NeoSwapsError::InvalidSpotPrices);
+    }
+    ln(to_fixed(spot_price)?)
+}
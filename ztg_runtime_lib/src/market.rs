@@ -0,0 +1,336 @@
+//! Validated construction of market creation calls.
+//!
+//! [`MarketBuilder`] checks the rules of zrml-prediction-markets before a market is created, so
+//! that a contract can return a descriptive error instead of a failed dispatch:
+//! https://github.com/zeitgeistpm/zeitgeist/tree/release-v0.5.0/zrml/prediction-markets/src/lib.rs
+
+use ink::prelude::vec::Vec;
+use ink::primitives::AccountId;
+use scale::{Decode, Encode};
+use sp_runtime::Perbill;
+
+use crate::lmsr::BASE;
+use crate::primitives::*;
+use crate::runtime_structs::{Balance, PredictionMarketsCall, RuntimeCall};
+
+/// The number of blocks per hour, with 12 second blocks.
+const BLOCKS_PER_HOUR: u64 = 300;
+/// The number of blocks per day, with 12 second blocks.
+const BLOCKS_PER_DAY: u64 = 24 * BLOCKS_PER_HOUR;
+
+/// The bounds the runtime puts on markets. Durations are in blocks.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MarketLimits {
+    pub min_categories: u16,
+    pub max_categories: u16,
+    pub max_creator_fee: Perbill,
+    pub max_grace_period: u64,
+    pub min_oracle_duration: u64,
+    pub max_oracle_duration: u64,
+    pub min_dispute_duration: u64,
+    pub max_dispute_duration: u64,
+}
+
+impl Default for MarketLimits {
+    /// The limits of the Zeitgeist runtime.
+    /// https://github.com/zeitgeistpm/zeitgeist/tree/release-v0.5.0/runtime/zeitgeist/src/parameters.rs
+    fn default() -> Self {
+        Self {
+            min_categories: 2,
+            max_categories: 64,
+            max_creator_fee: Perbill::from_percent(1),
+            max_grace_period: 20 * BLOCKS_PER_DAY,
+            min_oracle_duration: BLOCKS_PER_HOUR,
+            max_oracle_duration: 14 * BLOCKS_PER_DAY,
+            min_dispute_duration: 12 * BLOCKS_PER_HOUR,
+            max_dispute_duration: 30 * BLOCKS_PER_DAY,
+        }
+    }
+}
+
+/// The reasons a [`MarketBuilder`] rejects a market.
+#[derive(Clone, Copy, Debug, Decode, Encode, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum MarketBuilderError {
+    /// Only ZTG and foreign assets can be the base asset of a market.
+    InvalidBaseAsset,
+    /// The creator fee is above the maximum.
+    CreatorFeeTooHigh { max: Perbill },
+    /// The market period does not end after it starts.
+    EmptyPeriod,
+    GracePeriodTooLong { max: u64 },
+    OracleDurationTooShort { min: u64 },
+    OracleDurationTooLong { max: u64 },
+    DisputeDurationTooShort { min: u64 },
+    DisputeDurationTooLong { max: u64 },
+    /// A market without dispute mechanism can not have a dispute duration.
+    DisputeDurationOnTrustedMarket,
+    /// A market without dispute mechanism has to be approved by the advisory committee.
+    TrustedMarketNotAdvised,
+    NotEnoughCategories { min: u16 },
+    TooManyCategories { max: u16 },
+    /// The range of a scalar market does not end after it starts.
+    InvalidScalarRange,
+    /// Parimutuel markets must be categorical.
+    ParimutuelNotCategorical,
+    /// Markets with a pool must use the LMSR scoring rule.
+    PoolRequiresLmsr,
+    /// The pool needs one spot price per outcome.
+    SpotPriceCountMismatch { expected: u16 },
+    /// The spot prices of the pool must sum to one.
+    SpotPricesNotSummingToOne,
+    /// The pool needs liquidity.
+    ZeroLiquidity,
+}
+
+/// Builds `CreateMarket`, `EditMarket` and `CreateMarketAndDeployPool` calls and validates them
+/// first.
+///
+/// Defaults to a permissionless LMSR market in ZTG without creator fee, with court disputes and
+/// the shortest oracle and dispute durations of the [`MarketLimits`].
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```ignore
+/// # use ztg_runtime_lib::{market::*, primitives::*};
+/// let call = MarketBuilder::new(oracle, MarketPeriod::Block(100..1_000), metadata, MarketType::Categorical(2))
+///     .creator_fee(Perbill::from_parts(5_000_000))
+///     .create_market_and_deploy_pool(100 * BASE, vec![BASE / 2, BASE / 2], BASE / 100)?;
+/// self.env().call_runtime(&call)?;
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct MarketBuilder {
+    base_asset: ZeitgeistAsset,
+    creator_fee: Perbill,
+    oracle: AccountId,
+    period: MarketPeriod,
+    deadlines: Deadlines,
+    metadata: MultiHash,
+    creation: MarketCreation,
+    market_type: MarketType,
+    dispute_mechanism: Option<MarketDisputeMechanism>,
+    scoring_rule: ScoringRule,
+    limits: MarketLimits,
+}
+
+impl MarketBuilder {
+    pub fn new(
+        oracle: impl Into<AccountId>,
+        period: MarketPeriod,
+        metadata: MultiHash,
+        market_type: MarketType,
+    ) -> Self {
+        let limits = MarketLimits::default();
+        Self {
+            base_asset: ZeitgeistAsset::Ztg,
+            creator_fee: Perbill::zero(),
+            oracle: oracle.into(),
+            period,
+            deadlines: Deadlines {
+                grace_period: 0,
+                oracle_duration: limits.min_oracle_duration,
+                dispute_duration: limits.min_dispute_duration,
+            },
+            metadata,
+            creation: MarketCreation::Permissionless,
+            market_type,
+            dispute_mechanism: Some(MarketDisputeMechanism::Court),
+            scoring_rule: ScoringRule::Lmsr,
+            limits,
+        }
+    }
+
+    /// Creates a builder for a market whose oracle is a Zeitgeist or Battery Station address.
+    #[cfg(feature = "std")]
+    pub fn from_ss58(
+        oracle: &str,
+        period: MarketPeriod,
+        metadata: MultiHash,
+        market_type: MarketType,
+    ) -> Result<Self, crate::ss58::Ss58Error> {
+        Ok(Self::new(crate::ss58::account_id(oracle)?, period, metadata, market_type))
+    }
+
+    /// Sets the collateral of the market.
+    pub fn base_asset(mut self, base_asset: ZeitgeistAsset) -> Self {
+        self.base_asset = base_asset;
+        self
+    }
+
+    /// Sets the fee paid to the creator on every trade.
+    pub fn creator_fee(mut self, creator_fee: Perbill) -> Self {
+        self.creator_fee = creator_fee;
+        self
+    }
+
+    pub fn deadlines(mut self, deadlines: Deadlines) -> Self {
+        self.deadlines = deadlines;
+        self
+    }
+
+    pub fn creation(mut self, creation: MarketCreation) -> Self {
+        self.creation = creation;
+        self
+    }
+
+    /// Sets how reports are disputed. `None` creates a trusted market, which needs a dispute
+    /// duration of zero.
+    pub fn dispute_mechanism(mut self, dispute_mechanism: Option<MarketDisputeMechanism>) -> Self {
+        self.dispute_mechanism = dispute_mechanism;
+        self
+    }
+
+    pub fn scoring_rule(mut self, scoring_rule: ScoringRule) -> Self {
+        self.scoring_rule = scoring_rule;
+        self
+    }
+
+    /// Validates against other limits than the Zeitgeist runtime's, e.g. of a test network.
+    pub fn limits(mut self, limits: MarketLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// The number of outcome assets of the market.
+    fn asset_count(&self) -> u16 {
+        match self.market_type {
+            MarketType::Categorical(categories) => categories,
+            MarketType::Scalar(_) => 2,
+        }
+    }
+
+    /// Checks the market against the rules of the runtime.
+    pub fn validate(&self) -> Result<(), MarketBuilderError> {
+        let limits = &self.limits;
+        if !self.base_asset.is_base_asset() {
+            return Err(MarketBuilderError::InvalidBaseAsset);
+        }
+        if self.creator_fee > limits.max_creator_fee {
+            return Err(MarketBuilderError::CreatorFeeTooHigh { max: limits.max_creator_fee });
+        }
+        let (MarketPeriod::Block(period) | MarketPeriod::Timestamp(period)) = &self.period;
+        if period.start >= period.end {
+            return Err(MarketBuilderError::EmptyPeriod);
+        }
+
+        let deadlines = &self.deadlines;
+        if deadlines.grace_period > limits.max_grace_period {
+            return Err(MarketBuilderError::GracePeriodTooLong { max: limits.max_grace_period });
+        }
+        if deadlines.oracle_duration < limits.min_oracle_duration {
+            return Err(MarketBuilderError::OracleDurationTooShort {
+                min: limits.min_oracle_duration,
+            });
+        }
+        if deadlines.oracle_duration > limits.max_oracle_duration {
+            return Err(MarketBuilderError::OracleDurationTooLong {
+                max: limits.max_oracle_duration,
+            });
+        }
+        if self.dispute_mechanism.is_none() {
+            if deadlines.dispute_duration != 0 {
+                return Err(MarketBuilderError::DisputeDurationOnTrustedMarket);
+            }
+            if self.creation != MarketCreation::Advised {
+                return Err(MarketBuilderError::TrustedMarketNotAdvised);
+            }
+        } else if deadlines.dispute_duration < limits.min_dispute_duration {
+            return Err(MarketBuilderError::DisputeDurationTooShort {
+                min: limits.min_dispute_duration,
+            });
+        } else if deadlines.dispute_duration > limits.max_dispute_duration {
+            return Err(MarketBuilderError::DisputeDurationTooLong {
+                max: limits.max_dispute_duration,
+            });
+        }
+
+        match &self.market_type {
+            MarketType::Categorical(categories) if *categories < limits.min_categories => {
+                return Err(MarketBuilderError::NotEnoughCategories { min: limits.min_categories });
+            }
+            MarketType::Categorical(categories) if *categories > limits.max_categories => {
+                return Err(MarketBuilderError::TooManyCategories { max: limits.max_categories });
+            }
+            MarketType::Scalar(range) if range.start() >= range.end() => {
+                return Err(MarketBuilderError::InvalidScalarRange);
+            }
+            MarketType::Scalar(_) if self.scoring_rule == ScoringRule::Parimutuel => {
+                return Err(MarketBuilderError::ParimutuelNotCategorical);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Returns a validated `CreateMarket` call.
+    pub fn create_market(self) -> Result<RuntimeCall, MarketBuilderError> {
+        self.validate()?;
+        Ok(RuntimeCall::PredictionMarkets(PredictionMarketsCall::CreateMarket {
+            base_asset: self.base_asset,
+            creator_fee: self.creator_fee,
+            oracle: self.oracle,
+            period: self.period,
+            deadlines: self.deadlines,
+            metadata: self.metadata,
+            creation: self.creation,
+            market_type: self.market_type,
+            dispute_mechanism: self.dispute_mechanism,
+            scoring_rule: self.scoring_rule,
+        }))
+    }
+
+    /// Returns a validated `EditMarket` call, which replaces the market `market_id` after the
+    /// advisory committee requested an edit. The creator fee and creation can not be edited.
+    pub fn edit_market(self, market_id: MarketId) -> Result<RuntimeCall, MarketBuilderError> {
+        self.validate()?;
+        Ok(RuntimeCall::PredictionMarkets(PredictionMarketsCall::EditMarket {
+            base_asset: self.base_asset,
+            market_id,
+            oracle: self.oracle,
+            period: self.period,
+            deadlines: self.deadlines,
+            metadata: self.metadata,
+            market_type: self.market_type,
+            dispute_mechanism: self.dispute_mechanism,
+            scoring_rule: self.scoring_rule,
+        }))
+    }
+
+    /// Returns a validated `CreateMarketAndDeployPool` call, which deploys a neo-swaps pool with
+    /// `amount` complete sets at `spot_prices`.
+    pub fn create_market_and_deploy_pool(
+        self,
+        amount: Balance,
+        spot_prices: Vec<Balance>,
+        swap_fee: Balance,
+    ) -> Result<RuntimeCall, MarketBuilderError> {
+        self.validate()?;
+        if self.scoring_rule != ScoringRule::Lmsr {
+            return Err(MarketBuilderError::PoolRequiresLmsr);
+        }
+        if spot_prices.len() != usize::from(self.asset_count()) {
+            return Err(MarketBuilderError::SpotPriceCountMismatch { expected: self.asset_count() });
+        }
+        if spot_prices.iter().try_fold(0u128, |sum, p| sum.checked_add(*p)) != Some(BASE) {
+            return Err(MarketBuilderError::SpotPricesNotSummingToOne);
+        }
+        if amount == 0 {
+            return Err(MarketBuilderError::ZeroLiquidity);
+        }
+        Ok(RuntimeCall::PredictionMarkets(PredictionMarketsCall::CreateMarketAndDeployPool {
+            base_asset: self.base_asset,
+            creator_fee: self.creator_fee,
+            oracle: self.oracle,
+            period: self.period,
+            deadlines: self.deadlines,
+            metadata: self.metadata,
+            market_type: self.market_type,
+            dispute_mechanism: self.dispute_mechanism,
+            amount,
+            spot_prices,
+            swap_fee,
+        }))
+    }
+}
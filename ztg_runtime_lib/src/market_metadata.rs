@@ -0,0 +1,177 @@
+//! Market metadata as stored on IPFS, and its [`MultiHash`].
+//!
+//! The Zeitgeist SDK adds the metadata JSON to IPFS hashed with sha3-384, which creates a CIDv1
+//! with the raw codec. Markets store the multihash of that CID, which the indexer and the UI
+//! turn back into the CID to fetch the metadata:
+//! https://github.com/zeitgeistpm/sdk-next
+
+use core::fmt;
+use serde::{Deserialize, Serialize};
+use sha3::{Digest, Sha3_384};
+
+use crate::primitives::MultiHash;
+
+/// The multicodec of sha3-384, followed by the length of its digest.
+const SHA3_384_PREFIX: [u8; 2] = [0x15, 0x30];
+/// The version of CIDs with a multihash other than sha2-256.
+const CID_VERSION: u8 = 1;
+/// The multicodec of raw binary content, which IPFS uses for content stored in one block.
+const RAW_CODEC: u8 = 0x55;
+/// The multibase prefix of lowercase base32 without padding, the default of CIDv1.
+const BASE32_PREFIX: char = 'b';
+/// The multibase prefix of uppercase base32 without padding, e.g. of `ipfs cid format -b base32upper`.
+const BASE32_UPPER_PREFIX: char = 'B';
+const BASE32_ALPHABET: &[u8; 32] = b"abcdefghijklmnopqrstuvwxyz234567";
+
+/// The metadata of a market, in the format of the Zeitgeist SDK. Fields are serialized in
+/// declaration order and omitted if unset, like `JSON.stringify` does.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarketMetadata {
+    pub question: String,
+    pub description: String,
+    /// A short, URL-friendly name of the market.
+    pub slug: String,
+    /// The outcomes of a categorical market, or `Long` and `Short` of a scalar market.
+    pub categories: Vec<CategoryMetadata>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub img: Option<String>,
+    /// How the UI displays the outcome of a scalar market, `number` or `date`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scalar_type: Option<String>,
+}
+
+/// The metadata of a market outcome.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CategoryMetadata {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ticker: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub img: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+}
+
+impl MarketMetadata {
+    /// Returns the JSON that is stored on IPFS.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("metadata only contains strings; qed")
+    }
+
+    /// Parses metadata fetched from IPFS.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Returns the multihash of the metadata, as passed to `CreateMarket`.
+    pub fn multihash(&self) -> MultiHash {
+        multihash(self.to_json().as_bytes())
+    }
+
+    /// Returns the CID the metadata is stored at on IPFS.
+    pub fn cid(&self) -> String {
+        multihash_to_cid(&self.multihash())
+    }
+}
+
+/// The reasons a CID can not be turned into a [`MultiHash`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CidError {
+    /// The CID is not encoded in base32, e.g. a CIDv0 starting with `Qm`.
+    UnsupportedMultibase,
+    InvalidBase32,
+    UnsupportedVersion,
+    /// The content is not stored as raw binary.
+    UnsupportedCodec,
+    /// The content is not hashed with sha3-384.
+    UnsupportedHash,
+}
+
+impl fmt::Display for CidError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::UnsupportedMultibase => "CID is not encoded in base32",
+            Self::InvalidBase32 => "CID is not valid base32",
+            Self::UnsupportedVersion => "CID is not a CIDv1",
+            Self::UnsupportedCodec => "CID does not use the raw codec",
+            Self::UnsupportedHash => "CID does not hash with sha3-384",
+        })
+    }
+}
+
+impl std::error::Error for CidError {}
+
+/// Returns the sha3-384 multihash of `content`.
+pub fn multihash(content: &[u8]) -> MultiHash {
+    let mut bytes = [0; 50];
+    bytes[..2].copy_from_slice(&SHA3_384_PREFIX);
+    bytes[2..].copy_from_slice(&Sha3_384::digest(content));
+    MultiHash::Sha3_384(bytes)
+}
+
+/// Returns the CID of the content with the multihash, e.g. `bafkrw...`.
+pub fn multihash_to_cid(multihash: &MultiHash) -> String {
+    let MultiHash::Sha3_384(bytes) = multihash;
+    let mut cid = vec![CID_VERSION, RAW_CODEC];
+    cid.extend_from_slice(bytes);
+
+    let mut encoded = String::from(BASE32_PREFIX);
+    for chunk in cid.chunks(5) {
+        let mut buffer = [0u8; 5];
+        buffer[..chunk.len()].copy_from_slice(chunk);
+        let bits = u64::from_be_bytes([0, 0, 0, buffer[0], buffer[1], buffer[2], buffer[3], buffer[4]]);
+        // Every 5 bits are one character, without padding for the last chunk.
+        let chars = (chunk.len() * 8).div_ceil(5);
+        for i in 0..chars {
+            let index = (bits >> (35 - 5 * i)) & 0b11111;
+            encoded.push(BASE32_ALPHABET[index as usize] as char);
+        }
+    }
+    encoded
+}
+
+/// Returns the multihash of a CID created by [`multihash_to_cid`] or the Zeitgeist SDK, in
+/// lowercase (`b...`) or uppercase (`B...`) base32.
+pub fn cid_to_multihash(cid: &str) -> Result<MultiHash, CidError> {
+    let cid = cid.trim();
+    let (encoded, uppercase) = match cid.strip_prefix(BASE32_PREFIX) {
+        Some(encoded) => (encoded, false),
+        None => (cid.strip_prefix(BASE32_UPPER_PREFIX).ok_or(CidError::UnsupportedMultibase)?, true),
+    };
+
+    let mut bytes = Vec::with_capacity(encoded.len() * 5 / 8);
+    let (mut bits, mut bit_count) = (0u32, 0);
+    for c in encoded.bytes() {
+        // The case is part of the multibase, so it must not change within the CID.
+        if c.is_ascii_alphabetic() && c.is_ascii_uppercase() != uppercase {
+            return Err(CidError::InvalidBase32);
+        }
+        let value = BASE32_ALPHABET
+            .iter()
+            .position(|a| *a == c.to_ascii_lowercase())
+            .ok_or(CidError::InvalidBase32)?;
+        bits = (bits << 5) | value as u32;
+        bit_count += 5;
+        if bit_count >= 8 {
+            bit_count -= 8;
+            bytes.push((bits >> bit_count) as u8);
+            bits &= (1 << bit_count) - 1;
+        }
+    }
+
+    match bytes.as_slice() {
+        [CID_VERSION, RAW_CODEC, multihash @ ..] => {
+            let multihash: [u8; 50] =
+                multihash.try_into().map_err(|_| CidError::UnsupportedHash)?;
+            if multihash[..2] != SHA3_384_PREFIX {
+                return Err(CidError::UnsupportedHash);
+            }
+            Ok(MultiHash::Sha3_384(multihash))
+        }
+        [CID_VERSION, ..] => Err(CidError::UnsupportedCodec),
+        _ => Err(CidError::UnsupportedVersion),
+    }
+}
@@ -0,0 +1,331 @@
+//! Verification of [`RuntimeCall`] against the metadata of a live runtime.
+//!
+//! Fetch the metadata of a node with `state_getMetadata` and store the SCALE-encoded bytes:
+//!
+//! ```sh
+//! curl -s -H "Content-Type: application/json" \
+//!     -d '{"id":1,"jsonrpc":"2.0","method":"state_getMetadata","params":[]}' \
+//!     https://zeitgeist-rpc.dwellir.com | jq -r '.result[2:]' | xxd -r -p > zeitgeist.scale
+//! ```
+//!
+//! Then check it with `cargo run --bin ztg-verify-metadata -- zeitgeist.scale`.
+
+use core::fmt;
+use frame_metadata::{RuntimeMetadata, RuntimeMetadataPrefixed};
+use scale::Decode;
+use scale_info::{form::PortableForm, PortableRegistry, TypeDef, Variant};
+
+use crate::{errors::ZeitgeistError, runtime_structs::RuntimeCall};
+
+/// A difference between [`RuntimeCall`] and the runtime metadata.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Mismatch {
+    /// The runtime has no pallet with calls of this name.
+    MissingPallet { pallet: String },
+    /// The pallet is at a different index in the runtime.
+    PalletIndex { pallet: String, ours: u8, runtime: u8 },
+    /// The pallet has no call of this name.
+    MissingCall { pallet: String, call: String },
+    /// The call is at a different index in the pallet.
+    CallIndex { pallet: String, call: String, ours: u8, runtime: u8 },
+    /// The call's fields have different names or are in a different order.
+    Fields { pallet: String, call: String, ours: Vec<String>, runtime: Vec<String> },
+    /// A field is compact encoded on one side only.
+    Compact { pallet: String, call: String, field: String, ours: bool, runtime: bool },
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingPallet { pallet } => write!(f, "{pallet}: pallet not found in runtime"),
+            Self::PalletIndex { pallet, ours, runtime } => {
+                write!(f, "{pallet}: pallet index is {ours}, runtime has {runtime}")
+            }
+            Self::MissingCall { pallet, call } => {
+                write!(f, "{pallet}::{call}: call not found in runtime")
+            }
+            Self::CallIndex { pallet, call, ours, runtime } => {
+                write!(f, "{pallet}::{call}: call index is {ours}, runtime has {runtime}")
+            }
+            Self::Fields { pallet, call, ours, runtime } => write!(
+                f,
+                "{pallet}::{call}: fields are ({}), runtime has ({})",
+                ours.join(", "),
+                runtime.join(", ")
+            ),
+            Self::Compact { pallet, call, field, ours, runtime } => write!(
+                f,
+                "{pallet}::{call}: field `{field}` is {}compact, runtime is {}compact",
+                if *ours { "" } else { "not " },
+                if *runtime { "" } else { "not " }
+            ),
+        }
+    }
+}
+
+/// A difference between the pallet errors of [`ZeitgeistError`] and the runtime metadata.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ErrorMismatch {
+    /// The pallet has no error of this name.
+    MissingError { pallet: String, error: String },
+    /// The error is at a different index in the pallet.
+    ErrorIndex { pallet: String, error: String, ours: u8, runtime: u8 },
+}
+
+impl fmt::Display for ErrorMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingError { pallet, error } => {
+                write!(f, "{pallet}::{error}: error not found in runtime")
+            }
+            Self::ErrorIndex { pallet, error, ours, runtime } => {
+                write!(f, "{pallet}::{error}: error index is {ours}, runtime has {runtime}")
+            }
+        }
+    }
+}
+
+/// The errors that can occur when reading runtime metadata.
+#[derive(Debug)]
+pub enum MetadataError {
+    /// The bytes are not SCALE-encoded runtime metadata.
+    Decode(scale::Error),
+    /// The metadata has a version other than V14 or V15.
+    UnsupportedVersion(u32),
+}
+
+impl fmt::Display for MetadataError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Decode(e) => write!(f, "could not decode runtime metadata: {e}"),
+            Self::UnsupportedVersion(v) => write!(f, "unsupported metadata version V{v}"),
+        }
+    }
+}
+
+impl std::error::Error for MetadataError {}
+
+/// The calls of the pallets of a runtime, independent of the metadata version.
+pub struct RuntimeCalls {
+    pub types: PortableRegistry,
+    /// The name, index and call enum type id of every pallet with calls.
+    pub pallets: Vec<(String, u8, u32)>,
+    /// The name and error enum type id of every pallet with errors.
+    pub errors: Vec<(String, u32)>,
+    /// The spec name and spec version of the `System` pallet's `Version` constant.
+    pub spec: Option<(String, u32)>,
+}
+
+impl RuntimeCalls {
+    /// Decodes `RuntimeMetadataPrefixed` bytes as returned by `state_getMetadata`.
+    pub fn decode(bytes: &[u8]) -> Result<Self, MetadataError> {
+        let metadata =
+            RuntimeMetadataPrefixed::decode(&mut &bytes[..]).map_err(MetadataError::Decode)?;
+        Self::from_metadata(metadata.1)
+    }
+
+    /// Extracts the pallet calls of V14 or V15 metadata.
+    pub fn from_metadata(metadata: RuntimeMetadata) -> Result<Self, MetadataError> {
+        match metadata {
+            RuntimeMetadata::V14(metadata) => Ok(Self {
+                pallets: metadata
+                    .pallets
+                    .iter()
+                    .filter_map(|p| p.calls.as_ref().map(|c| (p.name.clone(), p.index, c.ty.id)))
+                    .collect(),
+                errors: metadata
+                    .pallets
+                    .iter()
+                    .filter_map(|p| p.error.as_ref().map(|e| (p.name.clone(), e.ty.id)))
+                    .collect(),
+                spec: metadata
+                    .pallets
+                    .iter()
+                    .filter(|p| p.name == "System")
+                    .flat_map(|p| p.constants.iter())
+                    .find(|c| c.name == "Version")
+                    .and_then(|c| Self::spec(&c.value)),
+                types: metadata.types,
+            }),
+            RuntimeMetadata::V15(metadata) => Ok(Self {
+                pallets: metadata
+                    .pallets
+                    .iter()
+                    .filter_map(|p| p.calls.as_ref().map(|c| (p.name.clone(), p.index, c.ty.id)))
+                    .collect(),
+                errors: metadata
+                    .pallets
+                    .iter()
+                    .filter_map(|p| p.error.as_ref().map(|e| (p.name.clone(), e.ty.id)))
+                    .collect(),
+                spec: metadata
+                    .pallets
+                    .iter()
+                    .filter(|p| p.name == "System")
+                    .flat_map(|p| p.constants.iter())
+                    .find(|c| c.name == "Version")
+                    .and_then(|c| Self::spec(&c.value)),
+                types: metadata.types,
+            }),
+            other => Err(MetadataError::UnsupportedVersion(other.version())),
+        }
+    }
+
+    /// Decodes the spec name and spec version, the leading fields of an encoded `RuntimeVersion`.
+    fn spec(value: &[u8]) -> Option<(String, u32)> {
+        let (spec_name, _impl_name, _authoring_version, spec_version) =
+            <(String, String, u32, u32)>::decode(&mut &value[..]).ok()?;
+        Some((spec_name, spec_version))
+    }
+
+    /// Returns the registry of this library's [`RuntimeCall`] and its type id.
+    pub fn ours() -> (PortableRegistry, u32) {
+        let mut registry = scale_info::Registry::new();
+        let id = registry.register_type(&scale_info::meta_type::<RuntimeCall>()).id;
+        (registry.into(), id)
+    }
+
+    /// Returns the calls of this library's [`RuntimeCall`] as if they were a runtime's, e.g. to
+    /// generate [`crate::golden`] vectors without a metadata file.
+    pub fn library() -> Self {
+        let (types, runtime_call) = Self::ours();
+        let pallets = variants(&types, runtime_call)
+            .iter()
+            .map(|pallet| (pallet.name.clone(), pallet.index, pallet.fields[0].ty.id))
+            .collect();
+        Self { types, pallets, errors: Vec::new(), spec: None }
+    }
+
+    /// Compares every pallet, call and field of [`RuntimeCall`] with the runtime.
+    pub fn verify(&self) -> Vec<Mismatch> {
+        let (types, runtime_call) = Self::ours();
+        let mut mismatches = Vec::new();
+
+        for pallet in variants(&types, runtime_call) {
+            let name = pallet.name.clone();
+            let Some((_, index, call_ty)) = self.pallets.iter().find(|(n, ..)| *n == name) else {
+                mismatches.push(Mismatch::MissingPallet { pallet: name });
+                continue;
+            };
+            if pallet.index != *index {
+                mismatches.push(Mismatch::PalletIndex {
+                    pallet: name.clone(),
+                    ours: pallet.index,
+                    runtime: *index,
+                });
+            }
+
+            let our_calls = variants(&types, pallet.fields[0].ty.id);
+            let runtime_calls = variants(&self.types, *call_ty);
+            for call in our_calls {
+                let call_name = snake_case(&call.name);
+                let Some(runtime) = runtime_calls.iter().find(|c| c.name == call_name) else {
+                    mismatches.push(Mismatch::MissingCall { pallet: name.clone(), call: call_name });
+                    continue;
+                };
+                if call.index != runtime.index {
+                    mismatches.push(Mismatch::CallIndex {
+                        pallet: name.clone(),
+                        call: call_name.clone(),
+                        ours: call.index,
+                        runtime: runtime.index,
+                    });
+                }
+
+                let field_names = |v: &Variant<PortableForm>| -> Vec<String> {
+                    v.fields.iter().map(|f| f.name.clone().unwrap_or_default()).collect()
+                };
+                let (ours, theirs) = (field_names(call), field_names(runtime));
+                if ours != theirs {
+                    mismatches.push(Mismatch::Fields {
+                        pallet: name.clone(),
+                        call: call_name,
+                        ours,
+                        runtime: theirs,
+                    });
+                    continue;
+                }
+
+                for (our_field, runtime_field) in call.fields.iter().zip(&runtime.fields) {
+                    let ours = is_compact(&types, our_field.ty.id);
+                    let runtime = is_compact(&self.types, runtime_field.ty.id);
+                    if ours != runtime {
+                        mismatches.push(Mismatch::Compact {
+                            pallet: name.clone(),
+                            call: call_name.clone(),
+                            field: our_field.name.clone().unwrap_or_default(),
+                            ours,
+                            runtime,
+                        });
+                    }
+                }
+            }
+        }
+
+        mismatches
+    }
+
+    /// Compares the pallet errors of [`ZeitgeistError`] with the runtime. Pallets without
+    /// errors in the runtime are skipped, missing pallets are reported by [`Self::verify`].
+    pub fn verify_errors(&self) -> Vec<ErrorMismatch> {
+        let mut registry = scale_info::Registry::new();
+        let id = registry.register_type(&scale_info::meta_type::<ZeitgeistError>()).id;
+        let types: PortableRegistry = registry.into();
+        let mut mismatches = Vec::new();
+
+        // Only the pallet variants wrap an error enum.
+        for pallet in variants(&types, id).iter().filter(|v| v.fields.len() == 1) {
+            let Some((_, error_ty)) = self.errors.iter().find(|(n, _)| *n == pallet.name) else {
+                continue;
+            };
+            let runtime_errors = variants(&self.types, *error_ty);
+            for error in variants(&types, pallet.fields[0].ty.id) {
+                match runtime_errors.iter().find(|e| e.name == error.name) {
+                    None => mismatches.push(ErrorMismatch::MissingError {
+                        pallet: pallet.name.clone(),
+                        error: error.name.clone(),
+                    }),
+                    Some(runtime) if runtime.index != error.index => {
+                        mismatches.push(ErrorMismatch::ErrorIndex {
+                            pallet: pallet.name.clone(),
+                            error: error.name.clone(),
+                            ours: error.index,
+                            runtime: runtime.index,
+                        })
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+
+        mismatches
+    }
+}
+
+/// Returns the variants of an enum type, or nothing if the type is not an enum.
+pub(crate) fn variants(types: &PortableRegistry, id: u32) -> &[Variant<PortableForm>] {
+    match types.resolve(id).map(|ty| &ty.type_def) {
+        Some(TypeDef::Variant(def)) => &def.variants,
+        _ => &[],
+    }
+}
+
+fn is_compact(types: &PortableRegistry, id: u32) -> bool {
+    matches!(types.resolve(id).map(|ty| &ty.type_def), Some(TypeDef::Compact(_)))
+}
+
+/// Converts a call variant name to the name of the pallet's dispatchable.
+pub(crate) fn snake_case(name: &str) -> String {
+    let mut snake = String::with_capacity(name.len() + 4);
+    for (i, c) in name.char_indices() {
+        if c.is_uppercase() {
+            if i != 0 {
+                snake.push('_');
+            }
+            snake.extend(c.to_lowercase());
+        } else {
+            snake.push(c);
+        }
+    }
+    snake
+}
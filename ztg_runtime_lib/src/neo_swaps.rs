@@ -0,0 +1,152 @@
+//! Slippage-protected trades with neo-swaps pools.
+//!
+//! The expected amounts are computed from a [`NeoSwapsPool`], e.g. the result of
+//! [`crate::chain_extension::ZeitgeistExtension::pool`], like zrml-neo-swaps computes them:
+//! https://github.com/zeitgeistpm/zeitgeist/tree/release-v0.5.0/zrml/neo-swaps/src/lib.rs
+
+use sp_runtime::{Perbill, Rounding};
+use sp_runtime::helpers_128bit::multiply_by_rational_with_rounding;
+
+use crate::errors::NeoSwapsError;
+use crate::lmsr;
+use crate::primitives::*;
+use crate::runtime_structs::{Balance, NeoSwapsCall, RuntimeCall};
+
+/// The denominator of a slippage in basis points.
+const MAX_BPS: u16 = 10_000;
+
+impl NeoSwapsPool {
+    /// The number of assets in the pool, which is the `asset_count` of buys and sells.
+    pub fn asset_count(&self) -> AssetIndexType {
+        self.reserves.len() as AssetIndexType
+    }
+
+    /// Returns the pool's reserve of an asset.
+    pub fn reserve(&self, asset: &ZeitgeistAsset) -> Result<Balance, NeoSwapsError> {
+        self.reserves
+            .iter()
+            .find(|(a, _)| a == asset)
+            .map(|(_, reserve)| *reserve)
+            .ok_or(NeoSwapsError::AssetNotFound)
+    }
+
+    /// Returns the spot price of an asset, with 10 decimals.
+    pub fn spot_price(&self, asset: &ZeitgeistAsset) -> Result<Balance, NeoSwapsError> {
+        lmsr::calculate_spot_price(self.reserve(asset)?, self.liquidity_parameter)
+    }
+
+    /// Returns the amount of `asset_out` received for `amount_in` collateral, after the swap
+    /// fee and the `creator_fee` of the market.
+    pub fn quote_buy(
+        &self,
+        asset_out: &ZeitgeistAsset,
+        amount_in: Balance,
+        creator_fee: Perbill,
+    ) -> Result<Balance, NeoSwapsError> {
+        if amount_in == 0 {
+            return Err(NeoSwapsError::ZeroAmount);
+        }
+        let amount_in_minus_fees = amount_in
+            .checked_sub(self.fees(amount_in, creator_fee)?)
+            .ok_or(NeoSwapsError::MathError)?;
+        let swap_amount_out = lmsr::calculate_swap_amount_out_for_buy(
+            self.reserve(asset_out)?,
+            amount_in_minus_fees,
+            self.liquidity_parameter,
+        )?;
+        swap_amount_out.checked_add(amount_in_minus_fees).ok_or(NeoSwapsError::MathError)
+    }
+
+    /// Returns the amount of collateral received for `amount_in` of `asset_in`, after the swap
+    /// fee and the `creator_fee` of the market.
+    pub fn quote_sell(
+        &self,
+        asset_in: &ZeitgeistAsset,
+        amount_in: Balance,
+        creator_fee: Perbill,
+    ) -> Result<Balance, NeoSwapsError> {
+        if amount_in == 0 {
+            return Err(NeoSwapsError::ZeroAmount);
+        }
+        let amount_out = lmsr::calculate_swap_amount_out_for_sell(
+            self.reserve(asset_in)?,
+            amount_in,
+            self.liquidity_parameter,
+        )?;
+        amount_out.checked_sub(self.fees(amount_out, creator_fee)?).ok_or(NeoSwapsError::MathError)
+    }
+
+    /// Buys `asset_out` with `amount_in` collateral, receiving at most `slippage_bps` basis
+    /// points less than [`Self::quote_buy`] after allowing for its [`lmsr::max_deviation`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```ignore
+    /// # use ztg_runtime_lib::{primitives::*, runtime_structs::*};
+    /// let market = self.env().extension().market(market_id).ok_or(Error::MarketNotFound)?;
+    /// let pool = self.env().extension().pool(market_id).ok_or(Error::PoolNotFound)?;
+    /// // Accept up to 1% slippage.
+    /// let call = pool.buy_with_max_slippage(market_id, asset, amount_in, market.creator_fee, 100)?;
+    /// self.env().call_runtime(&call)?;
+    /// ```
+    pub fn buy_with_max_slippage(
+        &self,
+        market_id: MarketId,
+        asset_out: ZeitgeistAsset,
+        amount_in: Balance,
+        creator_fee: Perbill,
+        slippage_bps: u16,
+    ) -> Result<RuntimeCall, NeoSwapsError> {
+        let amount_out = self.quote_buy(&asset_out, amount_in, creator_fee)?;
+        Ok(RuntimeCall::NeoSwaps(NeoSwapsCall::Buy {
+            market_id,
+            asset_count: self.asset_count(),
+            asset_out,
+            amount_in,
+            min_amount_out: with_slippage(amount_out, slippage_bps)?,
+        }))
+    }
+
+    /// Sells `amount_in` of `asset_in`, receiving at most `slippage_bps` basis points less than
+    /// [`Self::quote_sell`] after allowing for its [`lmsr::max_deviation`].
+    pub fn sell_with_max_slippage(
+        &self,
+        market_id: MarketId,
+        asset_in: ZeitgeistAsset,
+        amount_in: Balance,
+        creator_fee: Perbill,
+        slippage_bps: u16,
+    ) -> Result<RuntimeCall, NeoSwapsError> {
+        let amount_out = self.quote_sell(&asset_in, amount_in, creator_fee)?;
+        Ok(RuntimeCall::NeoSwaps(NeoSwapsCall::Sell {
+            market_id,
+            asset_count: self.asset_count(),
+            asset_in,
+            amount_in,
+            min_amount_out: with_slippage(amount_out, slippage_bps)?,
+        }))
+    }
+
+    /// The swap fee and the creator fee charged on `amount`.
+    fn fees(&self, amount: Balance, creator_fee: Perbill) -> Result<Balance, NeoSwapsError> {
+        let swap_fee = multiply_by_rational_with_rounding(
+            amount,
+            self.swap_fee,
+            lmsr::BASE,
+            Rounding::Down,
+        )
+        .ok_or(NeoSwapsError::MathError)?;
+        swap_fee.checked_add(creator_fee * amount).ok_or(NeoSwapsError::MathError)
+    }
+}
+
+/// Lowers `amount` by the deviation of the LMSR math from the pallet's and then by
+/// `slippage_bps` basis points, rounding down.
+fn with_slippage(amount: Balance, slippage_bps: u16) -> Result<Balance, NeoSwapsError> {
+    let amount = amount.saturating_sub(lmsr::max_deviation(amount));
+    let remaining = MAX_BPS.checked_sub(slippage_bps).ok_or(NeoSwapsError::NumericalLimits)?;
+    multiply_by_rational_with_rounding(amount, remaining.into(), MAX_BPS.into(), Rounding::Down)
+        .ok_or(NeoSwapsError::MathError)
+}
@@ -0,0 +1,154 @@
+/// The pallet indices of a Zeitgeist runtime.
+///
+/// These are the positions of the pallets inside the runtime's `construct_runtime!` block.
+/// https://github.com/zeitgeistpm/zeitgeist/blob/7ea631dbff5ea519a970c5bc0f3d3d143849d3b9/runtime/common/src/lib.rs#L274-L330
+///
+/// Call indices are defined by the pallets themselves and are pinned on the call enums in
+/// [`crate::runtime_structs`]. If a network ever runs a pallet release with different call
+/// indices, override the index of the affected variant with
+/// `#[cfg_attr(feature = "<network>", codec(index = N))]`.
+pub trait Network {
+    /// A human readable name of the network.
+    const NAME: &'static str;
+    /// The prefix of the network's SS58 addresses.
+    const SS58_PREFIX: u16;
+
+    const BALANCES: u8;
+    const UTILITY: u8;
+    const ASSET_MANAGER: u8;
+    const AUTHORIZED: u8;
+    const COURT: u8;
+    const SWAPS: u8;
+    const PREDICTION_MARKETS: u8;
+    const STYX: u8;
+    const GLOBAL_DISPUTES: u8;
+    const NEO_SWAPS: u8;
+    const ORDERBOOK: u8;
+    const PARIMUTUEL: u8;
+}
+
+/// The Zeitgeist parachain on Polkadot.
+pub enum Mainnet {}
+
+impl Network for Mainnet {
+    const NAME: &'static str = "Zeitgeist";
+    const SS58_PREFIX: u16 = 73;
+
+    const BALANCES: u8 = 10;
+    const UTILITY: u8 = 31;
+    const ASSET_MANAGER: u8 = 40;
+    const AUTHORIZED: u8 = 51;
+    const COURT: u8 = 52;
+    const SWAPS: u8 = 56;
+    const PREDICTION_MARKETS: u8 = 57;
+    const STYX: u8 = 58;
+    const GLOBAL_DISPUTES: u8 = 59;
+    const NEO_SWAPS: u8 = 60;
+    const ORDERBOOK: u8 = 61;
+    const PARIMUTUEL: u8 = 62;
+}
+
+/// The Battery Station test network.
+pub enum BatteryStation {}
+
+impl Network for BatteryStation {
+    const NAME: &'static str = "Battery Station";
+    const SS58_PREFIX: u16 = 42;
+
+    const BALANCES: u8 = 10;
+    const UTILITY: u8 = 31;
+    const ASSET_MANAGER: u8 = 40;
+    const AUTHORIZED: u8 = 51;
+    const COURT: u8 = 52;
+    const SWAPS: u8 = 56;
+    const PREDICTION_MARKETS: u8 = 57;
+    const STYX: u8 = 58;
+    const GLOBAL_DISPUTES: u8 = 59;
+    const NEO_SWAPS: u8 = 60;
+    const ORDERBOOK: u8 = 61;
+    const PARIMUTUEL: u8 = 62;
+}
+
+/// A standalone Zeitgeist development node (`zeitgeist --dev`).
+pub enum Dev {}
+
+impl Network for Dev {
+    const NAME: &'static str = "Zeitgeist Dev";
+    const SS58_PREFIX: u16 = 42;
+
+    const BALANCES: u8 = 10;
+    const UTILITY: u8 = 31;
+    const ASSET_MANAGER: u8 = 40;
+    const AUTHORIZED: u8 = 51;
+    const COURT: u8 = 52;
+    const SWAPS: u8 = 56;
+    const PREDICTION_MARKETS: u8 = 57;
+    const STYX: u8 = 58;
+    const GLOBAL_DISPUTES: u8 = 59;
+    const NEO_SWAPS: u8 = 60;
+    const ORDERBOOK: u8 = 61;
+    const PARIMUTUEL: u8 = 62;
+}
+
+#[cfg(any(
+    all(feature = "mainnet", feature = "battery-station"),
+    all(feature = "mainnet", feature = "dev"),
+    all(feature = "battery-station", feature = "dev"),
+))]
+compile_error!(
+    "Only one of the `mainnet`, `battery-station` and `dev` features of `ztg_runtime_lib` may be \
+     enabled. Check which crates in your dependency tree select a network."
+);
+
+/// The network that [`crate::runtime_structs::RuntimeCall`] is encoded for, selected with the
+/// `mainnet`, `battery-station` or `dev` feature. Defaults to [`Mainnet`].
+#[cfg(not(any(feature = "battery-station", feature = "dev")))]
+pub type Target = Mainnet;
+/// The network that [`crate::runtime_structs::RuntimeCall`] is encoded for, selected with the
+/// `mainnet`, `battery-station` or `dev` feature. Defaults to [`Mainnet`].
+#[cfg(all(feature = "battery-station", not(feature = "mainnet")))]
+pub type Target = BatteryStation;
+/// The network that [`crate::runtime_structs::RuntimeCall`] is encoded for, selected with the
+/// `mainnet`, `battery-station` or `dev` feature. Defaults to [`Mainnet`].
+#[cfg(all(feature = "dev", not(any(feature = "mainnet", feature = "battery-station"))))]
+pub type Target = Dev;
+
+/// Returns the pallet indices of `N` in the order of the `RuntimeCall` variants.
+pub const fn pallet_indices<N: Network>() -> [u8; 12] {
+    [
+        N::ASSET_MANAGER,
+        N::AUTHORIZED,
+        N::COURT,
+        N::SWAPS,
+        N::PREDICTION_MARKETS,
+        N::STYX,
+        N::GLOBAL_DISPUTES,
+        N::NEO_SWAPS,
+        N::ORDERBOOK,
+        N::PARIMUTUEL,
+        N::UTILITY,
+        N::BALANCES,
+    ]
+}
+
+// Only evaluated by the compile-time assertions below.
+#[allow(dead_code)]
+const fn has_unique_indices(indices: &[u8]) -> bool {
+    let mut i = 0;
+    while i < indices.len() {
+        let mut j = i + 1;
+        while j < indices.len() {
+            if indices[i] == indices[j] {
+                return false;
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+    true
+}
+
+// A pallet index used twice would make `RuntimeCall` ambiguous to decode.
+const _: () = assert!(has_unique_indices(&pallet_indices::<Mainnet>()));
+const _: () = assert!(has_unique_indices(&pallet_indices::<BatteryStation>()));
+const _: () = assert!(has_unique_indices(&pallet_indices::<Dev>()));
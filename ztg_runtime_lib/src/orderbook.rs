@@ -0,0 +1,290 @@
+//! Limit orders with a price and size, and the tracking of a contract's open orders.
+//!
+//! zrml-orderbook stores orders as amounts of a maker and a taker asset, one of which is the
+//! base asset of the market. [`LimitOrder`] expresses them as a side, the price of one outcome
+//! asset in the base asset, and the size in outcome assets:
+//! https://github.com/zeitgeistpm/zeitgeist/tree/release-v0.5.0/zrml/orderbook/src/lib.rs
+
+use ink::prelude::vec::Vec;
+use scale::{Decode, Encode};
+use sp_runtime::helpers_128bit::multiply_by_rational_with_rounding;
+use sp_runtime::Rounding;
+#[cfg(feature = "std")]
+use ink::storage::traits::StorageLayout;
+
+use crate::lmsr::BASE;
+use crate::primitives::*;
+use crate::runtime_structs::{Balance, Batch, OrderbookCall, RuntimeCall};
+
+/// An amount of an asset.
+pub type AssetAmount = (ZeitgeistAsset, Balance);
+
+/// Whether an order buys or sells outcome assets.
+#[derive(Clone, Copy, Debug, Decode, Encode, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, StorageLayout))]
+pub enum Side {
+    /// The maker gives the base asset for outcome assets.
+    Buy,
+    /// The maker gives outcome assets for the base asset.
+    Sell,
+}
+
+/// The errors of [`LimitOrder`] and [`OpenOrders`].
+#[derive(Clone, Copy, Debug, Decode, Encode, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum OrderError {
+    /// The maker or taker amount of the order is zero.
+    ZeroAmount,
+    /// The amounts of the order do not fit into a balance.
+    Overflow,
+    /// The order does not exchange an outcome asset for a base asset.
+    InvalidAssets,
+    /// The fill is larger than the remaining size of the order.
+    FillExceedsSize,
+    /// The order is not tracked.
+    NotTracked,
+    /// The order is already tracked.
+    AlreadyTracked,
+}
+
+/// An order to buy or sell `size` outcome assets at `price`, which has the 10 decimals of a
+/// balance.
+///
+/// The amounts are rounded in favor of the maker, so that the maker never gets a worse price:
+/// buys pay at most `price`, sells receive at least `price`.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```ignore
+/// # use ztg_runtime_lib::{orderbook::*, primitives::*};
+/// // Buy 10 of outcome 0 at 0.65 ZTG each.
+/// let order = LimitOrder {
+///     side: Side::Buy,
+///     outcome: ZeitgeistAsset::CategoricalOutcome(market_id, 0),
+///     base_asset: ZeitgeistAsset::Ztg,
+///     price: 6_500_000_000,
+///     size: 10 * BASE,
+/// };
+/// self.env().call_runtime(&order.place(market_id)?)?;
+/// ```
+#[derive(Clone, Debug, Decode, Encode, PartialEq)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, StorageLayout))]
+pub struct LimitOrder {
+    pub side: Side,
+    pub outcome: ZeitgeistAsset,
+    pub base_asset: ZeitgeistAsset,
+    pub price: Balance,
+    pub size: Balance,
+}
+
+impl LimitOrder {
+    /// Reads the side, price and size of an order, e.g. as returned by
+    /// [`crate::chain_extension::ZeitgeistExtension::order`].
+    pub fn from_order(order: &Order) -> Result<Self, OrderError> {
+        let (side, outcome, base_asset, size, value) =
+            match (order.maker_asset.is_base_asset(), order.taker_asset.is_base_asset()) {
+                (true, false) => (
+                    Side::Buy,
+                    &order.taker_asset,
+                    &order.maker_asset,
+                    order.taker_amount,
+                    order.maker_amount,
+                ),
+                (false, true) => (
+                    Side::Sell,
+                    &order.maker_asset,
+                    &order.taker_asset,
+                    order.maker_amount,
+                    order.taker_amount,
+                ),
+                _ => return Err(OrderError::InvalidAssets),
+            };
+        if size == 0 {
+            return Err(OrderError::ZeroAmount);
+        }
+        let price = match side {
+            Side::Buy => mul_div(value, BASE, size, Rounding::Down)?,
+            Side::Sell => mul_div(value, BASE, size, Rounding::Up)?,
+        };
+        Ok(Self { side, outcome: outcome.clone(), base_asset: base_asset.clone(), price, size })
+    }
+
+    /// Returns the amount of the base asset exchanged for `size` outcome assets.
+    pub fn value(&self, size: Balance) -> Result<Balance, OrderError> {
+        match self.side {
+            Side::Buy => mul_div(size, self.price, BASE, Rounding::Down),
+            Side::Sell => mul_div(size, self.price, BASE, Rounding::Up),
+        }
+    }
+
+    /// Returns the maker asset and amount, then the taker asset and amount of the order.
+    pub fn amounts(&self) -> Result<(AssetAmount, AssetAmount), OrderError> {
+        if !self.base_asset.is_base_asset() || self.outcome.is_base_asset() {
+            return Err(OrderError::InvalidAssets);
+        }
+        let value = self.value(self.size)?;
+        if value == 0 || self.size == 0 {
+            return Err(OrderError::ZeroAmount);
+        }
+        Ok(match self.side {
+            Side::Buy => ((self.base_asset.clone(), value), (self.outcome.clone(), self.size)),
+            Side::Sell => ((self.outcome.clone(), self.size), (self.base_asset.clone(), value)),
+        })
+    }
+
+    /// Places the order on the orderbook of market `market_id`.
+    pub fn place(&self, market_id: MarketId) -> Result<RuntimeCall, OrderError> {
+        let ((maker_asset, maker_amount), (taker_asset, taker_amount)) = self.amounts()?;
+        Ok(RuntimeCall::Orderbook(OrderbookCall::PlaceOrder {
+            market_id,
+            maker_asset,
+            maker_amount,
+            taker_asset,
+            taker_amount,
+        }))
+    }
+
+    /// Fills `size` outcome assets of the order with id `order_id`, or all of it if `size` is
+    /// `None`.
+    pub fn fill(&self, order_id: OrderId, size: Option<Balance>) -> Result<RuntimeCall, OrderError> {
+        let maker_partial_fill = match size {
+            Some(size) if size > self.size => return Err(OrderError::FillExceedsSize),
+            Some(size) if size < self.size => Some(self.maker_fill(size)?),
+            _ => None,
+        };
+        Ok(RuntimeCall::Orderbook(OrderbookCall::FillOrder { order_id, maker_partial_fill }))
+    }
+
+    /// The amount of the maker asset of a fill of `size` outcome assets.
+    fn maker_fill(&self, size: Balance) -> Result<Balance, OrderError> {
+        let fill = match self.side {
+            Side::Buy => self.value(size)?,
+            Side::Sell => size,
+        };
+        if fill == 0 {
+            return Err(OrderError::ZeroAmount);
+        }
+        Ok(fill)
+    }
+}
+
+fn mul_div(a: Balance, b: Balance, c: Balance, rounding: Rounding) -> Result<Balance, OrderError> {
+    multiply_by_rational_with_rounding(a, b, c, rounding).ok_or(OrderError::Overflow)
+}
+
+/// Tracks the orders a contract placed, to remove them later.
+///
+/// zrml-orderbook assigns the id of an order when it is placed, so the contract has to
+/// [`Self::insert`] it afterwards, e.g. after finding it with
+/// [`crate::chain_extension::ZeitgeistExtension::order`].
+///
+/// [`Self::remove`] and [`Self::remove_all`] stop tracking orders before their call is
+/// dispatched. The message that dispatches the call must return an `Err` if the dispatch fails,
+/// so that ink! reverts the change to the contract's storage, as `cancel_all` below does with `?`.
+/// A message that swallows the error forgets orders that are still open.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```ignore
+/// # use ztg_runtime_lib::orderbook::*;
+/// #[ink(storage)]
+/// pub struct MarketMaker {
+///     orders: OpenOrders,
+/// }
+///
+/// #[ink(message)]
+/// pub fn cancel_all(&mut self) -> Result<(), Error> {
+///     if let Some(call) = self.orders.remove_all() {
+///         self.env().call_runtime(&call)?;
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug, Default, Decode, Encode, PartialEq)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, StorageLayout))]
+pub struct OpenOrders {
+    orders: Vec<(OrderId, LimitOrder)>,
+}
+
+impl OpenOrders {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The tracked orders with their remaining size.
+    pub fn orders(&self) -> &[(OrderId, LimitOrder)] {
+        &self.orders
+    }
+
+    pub fn get(&self, order_id: OrderId) -> Option<&LimitOrder> {
+        self.orders.iter().find(|(id, _)| *id == order_id).map(|(_, order)| order)
+    }
+
+    fn position(&self, order_id: OrderId) -> Result<usize, OrderError> {
+        self.orders.iter().position(|(id, _)| *id == order_id).ok_or(OrderError::NotTracked)
+    }
+
+    /// Starts tracking a placed order.
+    pub fn insert(&mut self, order_id: OrderId, order: LimitOrder) -> Result<(), OrderError> {
+        if self.get(order_id).is_some() {
+            return Err(OrderError::AlreadyTracked);
+        }
+        self.orders.push((order_id, order));
+        Ok(())
+    }
+
+    /// Records that `size` outcome assets of an order were filled, and stops tracking it once
+    /// it is filled completely.
+    pub fn filled(&mut self, order_id: OrderId, size: Balance) -> Result<(), OrderError> {
+        let position = self.position(order_id)?;
+        let order = &mut self.orders[position].1;
+        order.size = order.size.checked_sub(size).ok_or(OrderError::FillExceedsSize)?;
+        if order.size == 0 {
+            self.orders.remove(position);
+        }
+        Ok(())
+    }
+
+    /// Updates an order with its state on chain, where `None` means that it was filled or
+    /// removed.
+    pub fn sync(&mut self, order_id: OrderId, order: Option<&Order>) -> Result<(), OrderError> {
+        let position = self.position(order_id)?;
+        match order {
+            Some(order) => self.orders[position].1.size = LimitOrder::from_order(order)?.size,
+            None => {
+                self.orders.remove(position);
+            }
+        }
+        Ok(())
+    }
+
+    /// Removes an order from the orderbook. The order is no longer tracked, so the message must
+    /// revert if the returned call fails.
+    pub fn remove(&mut self, order_id: OrderId) -> Result<RuntimeCall, OrderError> {
+        let position = self.position(order_id)?;
+        self.orders.remove(position);
+        Ok(RuntimeCall::Orderbook(OrderbookCall::RemoveOrder { order_id }))
+    }
+
+    /// Removes all orders from the orderbook, or returns `None` if there are none. The calls are
+    /// batched with `force_batch`, so orders that were filled in the meantime don't stop the
+    /// others from being removed.
+    ///
+    /// None of the orders is tracked afterwards, so the message must revert if the returned call
+    /// fails. `force_batch` itself succeeds when single removals fail, so orders whose removal
+    /// failed for another reason are forgotten as well.
+    pub fn remove_all(&mut self) -> Option<RuntimeCall> {
+        if self.orders.is_empty() {
+            return None;
+        }
+        let mut batch = Batch::new();
+        batch.extend(self.orders.drain(..).map(|(order_id, _)| {
+            RuntimeCall::Orderbook(OrderbookCall::RemoveOrder { order_id })
+        }));
+        Some(batch.force_batch())
+    }
+}
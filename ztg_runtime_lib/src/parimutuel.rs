@@ -0,0 +1,172 @@
+//! Payouts of parimutuel markets, computed like zrml-parimutuel.
+//! https://github.com/zeitgeistpm/zeitgeist/tree/release-v0.5.0/zrml/parimutuel/src/lib.rs
+//!
+//! Winners split the whole pot in proportion to their shares of the winning outcome. If no one
+//! bet on the winning outcome, every bet is refunded instead.
+
+use ink::prelude::vec::Vec;
+use scale::{Decode, Encode};
+use sp_runtime::helpers_128bit::multiply_by_rational_with_rounding;
+use sp_runtime::{Perbill, Rounding};
+#[cfg(feature = "std")]
+use ink::storage::traits::StorageLayout;
+
+use crate::errors::ParimutuelError;
+use crate::lmsr::BASE;
+use crate::primitives::*;
+use crate::runtime_structs::{Balance, ParimutelCall, RuntimeCall};
+
+/// The bets placed on a parimutuel market.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```ignore
+/// # use ztg_runtime_lib::parimutuel::*;
+/// // 100 ZTG were bet in total, 25 ZTG of them on the winning outcome 1.
+/// let pot = ParimutuelPot { total: 100 * BASE, outcome_totals: vec![75 * BASE, 25 * BASE] };
+/// // A bet of 10 ZTG on outcome 1 wins 40 ZTG.
+/// assert_eq!(pot.reward(1, 10 * BASE), Ok(40 * BASE));
+/// ```
+#[derive(Clone, Debug, Decode, Encode, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, StorageLayout))]
+pub struct ParimutuelPot {
+    /// The amount of the base asset held by the market's pot account.
+    pub total: Balance,
+    /// The total issuance of the parimutuel shares of each outcome, by category.
+    pub outcome_totals: Vec<Balance>,
+}
+
+impl ParimutuelPot {
+    /// Creates the empty pot of a market with `categories` outcomes.
+    pub fn new(categories: CategoryIndex) -> Self {
+        Self { total: 0, outcome_totals: ink::prelude::vec![0; categories.into()] }
+    }
+
+    /// Returns the total of the shares of `category`.
+    pub fn outcome_total(&self, category: CategoryIndex) -> Result<Balance, ParimutuelError> {
+        self.outcome_totals
+            .get(usize::from(category))
+            .copied()
+            .ok_or(ParimutuelError::InvalidOutcomeAsset)
+    }
+
+    /// Adds a bet of `amount` on `category` and returns the shares the bettor receives, which
+    /// is the amount minus the `creator_fee` of the market.
+    pub fn bet(
+        &mut self,
+        category: CategoryIndex,
+        amount: Balance,
+        creator_fee: Perbill,
+    ) -> Result<Balance, ParimutuelError> {
+        let shares = shares_for_bet(amount, creator_fee);
+        let outcome_total = self
+            .outcome_totals
+            .get_mut(usize::from(category))
+            .ok_or(ParimutuelError::InvalidOutcomeAsset)?;
+        *outcome_total = outcome_total.checked_add(shares).ok_or(ParimutuelError::Unexpected)?;
+        self.total = self.total.checked_add(shares).ok_or(ParimutuelError::Unexpected)?;
+        Ok(shares)
+    }
+
+    /// Whether the bets are refunded instead of rewarded, because no one bet on the
+    /// `winning_category`.
+    pub fn is_refundable(&self, winning_category: CategoryIndex) -> Result<bool, ParimutuelError> {
+        Ok(self.outcome_total(winning_category)? == 0)
+    }
+
+    /// Returns the payoff of one share of the `winning_category`, with 10 decimals.
+    pub fn payoff_ratio(&self, winning_category: CategoryIndex) -> Result<Balance, ParimutuelError> {
+        let outcome_total = self.outcome_total(winning_category)?;
+        if outcome_total == 0 {
+            return Err(ParimutuelError::NoRewardShareOutstanding);
+        }
+        if self.total == 0 {
+            return Err(ParimutuelError::NoRewardToDistribute);
+        }
+        bdiv(self.total, outcome_total)
+    }
+
+    /// Returns the reward of `ClaimRewards` for `winning_balance` shares of the
+    /// `winning_category`.
+    pub fn reward(
+        &self,
+        winning_category: CategoryIndex,
+        winning_balance: Balance,
+    ) -> Result<Balance, ParimutuelError> {
+        let payoff_ratio = self.payoff_ratio(winning_category)?;
+        if winning_balance == 0 {
+            return Err(ParimutuelError::NoWinningShares);
+        }
+        if winning_balance > self.outcome_total(winning_category)? {
+            return Err(ParimutuelError::Unexpected);
+        }
+        // The rounding could pay out slightly more than the pot holds.
+        Ok(bmul(payoff_ratio, winning_balance)?.min(self.total))
+    }
+
+    /// Returns the refund of `ClaimRefunds` for `refund_balance` shares of any outcome.
+    pub fn refund(
+        &self,
+        winning_category: CategoryIndex,
+        refund_balance: Balance,
+    ) -> Result<Balance, ParimutuelError> {
+        if !self.is_refundable(winning_category)? {
+            return Err(ParimutuelError::RefundNotAllowed);
+        }
+        if refund_balance == 0 {
+            return Err(ParimutuelError::RefundableBalanceIsZero);
+        }
+        Ok(refund_balance.min(self.total))
+    }
+
+    /// Returns the calls that claim everything `balances` pays out, where `balances` are the
+    /// shares held of each category, and the amount they pay out.
+    pub fn claim(
+        &self,
+        market_id: MarketId,
+        winning_category: CategoryIndex,
+        balances: &[Balance],
+    ) -> Result<(Vec<RuntimeCall>, Balance), ParimutuelError> {
+        if !self.is_refundable(winning_category)? {
+            let winning_balance = balances.get(usize::from(winning_category)).copied();
+            let reward = self.reward(winning_category, winning_balance.unwrap_or_default())?;
+            let call = RuntimeCall::Parimutuel(ParimutelCall::ClaimRewards { market_id });
+            return Ok((ink::prelude::vec![call], reward));
+        }
+
+        let mut calls = Vec::new();
+        let mut total = 0u128;
+        for (category, balance) in balances.iter().enumerate().filter(|(_, b)| **b != 0) {
+            let category = CategoryIndex::try_from(category).map_err(|_| ParimutuelError::InvalidOutcomeAsset)?;
+            total = total
+                .checked_add(self.refund(winning_category, *balance)?)
+                .ok_or(ParimutuelError::Unexpected)?;
+            calls.push(RuntimeCall::Parimutuel(ParimutelCall::ClaimRefunds {
+                refund_asset: ZeitgeistAsset::ParimutuelShare(market_id, category),
+            }));
+        }
+        if calls.is_empty() {
+            return Err(ParimutuelError::RefundableBalanceIsZero);
+        }
+        Ok((calls, total))
+    }
+}
+
+/// Returns the shares received for a bet of `amount`, after the `creator_fee` of the market.
+pub fn shares_for_bet(amount: Balance, creator_fee: Perbill) -> Balance {
+    amount.saturating_sub(creator_fee * amount)
+}
+
+/// Fixed-point division with 10 decimals, rounded like zeitgeist-primitives' `bdiv`.
+fn bdiv(a: Balance, b: Balance) -> Result<Balance, ParimutuelError> {
+    multiply_by_rational_with_rounding(a, BASE, b, Rounding::NearestPrefUp)
+        .ok_or(ParimutuelError::Unexpected)
+}
+
+/// Fixed-point multiplication with 10 decimals, rounded like zeitgeist-primitives' `bmul`.
+fn bmul(a: Balance, b: Balance) -> Result<Balance, ParimutuelError> {
+    multiply_by_rational_with_rounding(a, b, BASE, Rounding::NearestPrefUp)
+        .ok_or(ParimutuelError::Unexpected)
+}
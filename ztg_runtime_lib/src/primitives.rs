@@ -1,5 +1,8 @@
 use scale::{Encode, Decode};
 use core::ops::{Range, RangeInclusive};
+use ink::prelude::vec::Vec;
+use ink::primitives::AccountId;
+use sp_runtime::{MultiAddress, Perbill};
 #[cfg(feature = "std")]
 use ink::storage::traits::StorageLayout;
 
@@ -11,6 +14,28 @@ pub type OrderId = u128;
 pub type AssetIndexType = u16;
 pub type CategoryIndex = u16;
 
+/// Implements `StorageLayout` as a single leaf cell, for packed types with fields that have no
+/// layout of their own, like `Range`, `[u8; 50]`, `Perbill` or `MultiAddress`.
+macro_rules! impl_leaf_layout {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            #[cfg(feature = "std")]
+            impl ink::storage::traits::StorageLayout for $ty {
+                fn layout(key: &ink::primitives::Key) -> ink::metadata::layout::Layout {
+                    ink::metadata::layout::Layout::Leaf(
+                        ink::metadata::layout::LeafLayout::from_key::<Self>(
+                            ink::metadata::layout::LayoutKey::from(key),
+                        ),
+                    )
+                }
+            }
+        )*
+    };
+}
+pub(crate) use impl_leaf_layout;
+
+impl_leaf_layout!(MarketPeriod, MultiHash, MarketType, Market, ZtgAddress);
+
 /// A representation of a market's outcome.
 #[derive(Encode, Decode, Clone, PartialEq, Debug)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, StorageLayout))]
@@ -28,7 +53,7 @@ pub enum VoteItem {
 }
 
 /// Defines whether the period is represented as a blocknumber or a timestamp.
-#[derive(Clone, Decode, Encode, Eq, PartialEq)]
+#[derive(Clone, Debug, Decode, Encode, Eq, PartialEq)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
 pub enum MarketPeriod {
     Block(Range<u64>),
@@ -36,7 +61,7 @@ pub enum MarketPeriod {
 }
 
 /// Defines deadlines for market.
-#[derive(Clone, Decode, Encode, Eq, PartialEq)]
+#[derive(Clone, Debug, Decode, Encode, Eq, PartialEq)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, StorageLayout))]
 pub struct Deadlines {
     pub grace_period: u64,
@@ -52,7 +77,7 @@ pub enum MultiHash {
 }
 
 /// Defines the type of market creation.
-#[derive(Clone, Decode, Encode, PartialEq, Eq)]
+#[derive(Clone, Debug, Decode, Encode, PartialEq, Eq)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, StorageLayout))]
 pub enum MarketCreation {
     // A completely permissionless market that requires a higher
@@ -65,7 +90,7 @@ pub enum MarketCreation {
 
 /// Defines the type of market.
 /// All markets also have themin_assets_out `Invalid` resolution.
-#[derive(Clone, Decode, Encode, PartialEq, Eq)]
+#[derive(Clone, Debug, Decode, Encode, PartialEq, Eq)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
 pub enum MarketType {
     /// A market with a number of categorical outcomes.
@@ -75,7 +100,7 @@ pub enum MarketType {
 }
 
 /// How a market should resolve disputes
-#[derive(Clone, Decode, Encode, PartialEq, Eq)]
+#[derive(Clone, Debug, Decode, Encode, PartialEq, Eq)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, StorageLayout))]
 pub enum MarketDisputeMechanism {
     Authorized,
@@ -84,7 +109,7 @@ pub enum MarketDisputeMechanism {
 }
 
 /// The scoring methodology for a market.
-#[derive(Clone, Decode, Encode, PartialEq, Eq)]
+#[derive(Clone, Debug, Decode, Encode, PartialEq, Eq)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, StorageLayout))]
 pub enum ScoringRule {
     Lmsr,
@@ -92,15 +117,309 @@ pub enum ScoringRule {
     Parimutuel
 }
 
+/// The position of a scalar outcome asset.
+#[derive(Clone, Copy, Debug, Decode, Encode, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, StorageLayout))]
+pub enum ScalarPosition {
+    Long,
+    Short,
+}
+
 /// A type of asset.
-#[derive(scale::Encode, scale::Decode, Clone, PartialEq)]
-#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+#[derive(scale::Encode, scale::Decode, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, StorageLayout))]
 pub enum ZeitgeistAsset {
-    CategoricalOutcome(u128, u16),
-    ScalarOutcome, //(u128, ScalarPosition),
+    CategoricalOutcome(MarketId, CategoryIndex),
+    ScalarOutcome(MarketId, ScalarPosition),
+    // The runtime does not attach a payload to combinatorial outcomes yet.
     CombinatorialOutcome,
-    PoolShare, //(SerdeWrapper<PoolId>),
+    PoolShare(PoolId),
     Ztg,       // default
     ForeignAsset(u32),
-    ParimutuelShare(u128, u16)
+    ParimutuelShare(MarketId, CategoryIndex)
+}
+
+impl ZeitgeistAsset {
+    /// Whether the asset can be the base asset of a market, which is ZTG or a foreign asset.
+    pub fn is_base_asset(&self) -> bool {
+        matches!(self, Self::Ztg | Self::ForeignAsset(_))
+    }
+}
+
+/// The status of a market.
+#[derive(Clone, Copy, Debug, Decode, Encode, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, StorageLayout))]
+pub enum MarketStatus {
+    /// The market has been proposed and is waiting for approval from the advisory committee.
+    Proposed,
+    /// Trading on the market is active.
+    Active,
+    /// The market period has ended and the market is waiting for its outcome to be reported.
+    Closed,
+    /// The market has been reported.
+    Reported,
+    /// The market outcome is being disputed.
+    Disputed,
+    /// The market outcome has been resolved.
+    Resolved,
+}
+
+/// The outcome reported for a market, alongside who reported it and when.
+#[derive(Clone, Debug, Decode, Encode, PartialEq)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, StorageLayout))]
+pub struct Report {
+    pub at: u64,
+    pub by: AccountId,
+    pub outcome: OutcomeReport,
+}
+
+/// A bond reserved by an account for a market.
+#[derive(Clone, Debug, Decode, Encode, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, StorageLayout))]
+pub struct Bond {
+    pub who: AccountId,
+    pub value: u128,
+    pub is_settled: bool,
+}
+
+/// The bonds reserved for a market.
+#[derive(Clone, Debug, Decode, Encode, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, StorageLayout))]
+pub struct MarketBonds {
+    pub creation: Option<Bond>,
+    pub oracle: Option<Bond>,
+    pub outsider: Option<Bond>,
+    pub dispute: Option<Bond>,
+    pub close_request: Option<Bond>,
+    pub close_dispute: Option<Bond>,
+}
+
+/// The state of a scheduled early close.
+#[derive(Clone, Copy, Debug, Decode, Encode, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, StorageLayout))]
+pub enum EarlyCloseState {
+    ScheduledAsMarketCreator,
+    ScheduledAsOther,
+    Disputed,
+    Rejected,
+}
+
+/// An early close of a market, replacing its `old` period with the `new` one.
+#[derive(Clone, Debug, Decode, Encode, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, StorageLayout))]
+pub struct EarlyClose {
+    pub old: MarketPeriod,
+    pub new: MarketPeriod,
+    pub state: EarlyCloseState,
+}
+
+/// A prediction market, as stored by zrml-market-commons.
+/// https://github.com/zeitgeistpm/zeitgeist/tree/release-v0.5.0/primitives/src/market.rs
+#[derive(Clone, Debug, Decode, Encode, PartialEq)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct Market {
+    pub base_asset: ZeitgeistAsset,
+    /// Creator of this market.
+    pub creator: AccountId,
+    /// Creation type.
+    pub creation: MarketCreation,
+    /// A fee that is charged each trade and given to the market creator.
+    pub creator_fee: Perbill,
+    /// Oracle that reports the outcome of this market.
+    pub oracle: AccountId,
+    /// Metadata for the market, usually a content address of IPFS hosted JSON.
+    pub metadata: Vec<u8>,
+    /// The type of the market.
+    pub market_type: MarketType,
+    /// Market start and end.
+    pub period: MarketPeriod,
+    /// Market deadlines.
+    pub deadlines: Deadlines,
+    /// The scoring rule used for the market.
+    pub scoring_rule: ScoringRule,
+    /// The current status of the market.
+    pub status: MarketStatus,
+    /// The report of the market. Only `Some` if it has been reported.
+    pub report: Option<Report>,
+    /// The resolved outcome.
+    pub resolved_outcome: Option<OutcomeReport>,
+    /// See [`MarketDisputeMechanism`].
+    pub dispute_mechanism: Option<MarketDisputeMechanism>,
+    /// The bonds reserved for this market.
+    pub bonds: MarketBonds,
+    /// The time at which the market was closed early.
+    pub early_close: Option<EarlyClose>,
+}
+
+/// The state of a neo-swaps liquidity pool, as exposed by the chain extension.
+#[derive(Clone, Decode, Encode, PartialEq)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, StorageLayout))]
+pub struct NeoSwapsPool {
+    pub account_id: AccountId,
+    /// The amount of each outcome held by the pool, sorted by asset.
+    pub reserves: Vec<(ZeitgeistAsset, u128)>,
+    pub collateral: ZeitgeistAsset,
+    pub liquidity_parameter: u128,
+    pub swap_fee: u128,
+}
+
+/// An order placed on the orderbook.
+#[derive(Clone, Decode, Encode, PartialEq)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, StorageLayout))]
+pub struct Order {
+    pub market_id: MarketId,
+    pub maker: AccountId,
+    pub maker_asset: ZeitgeistAsset,
+    pub maker_amount: u128,
+    pub taker_asset: ZeitgeistAsset,
+    pub taker_amount: u128,
+}
+
+/// The status of a court case.
+#[derive(Clone, Debug, Decode, Encode, PartialEq)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, StorageLayout))]
+pub enum CourtStatus {
+    /// The court case has started.
+    Open,
+    /// The court case was closed, the winner vote item was determined.
+    Closed { winner: VoteItem },
+    /// The juror stakes from the court were reassigned.
+    Reassigned,
+}
+
+/// The type of vote item a court case is voted on.
+#[derive(Clone, Copy, Debug, Decode, Encode, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, StorageLayout))]
+pub enum VoteItemType {
+    Outcome,
+    Binary,
+}
+
+/// An appeal made to a court case.
+#[derive(Clone, Debug, Decode, Encode, PartialEq)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, StorageLayout))]
+pub struct AppealInfo {
+    pub backer: AccountId,
+    pub bond: u128,
+    pub appealed_vote_item: VoteItem,
+}
+
+/// The block numbers at which each phase of a court round ends.
+#[derive(Clone, Debug, Decode, Encode, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, StorageLayout))]
+pub struct RoundTiming {
+    pub pre_vote: u64,
+    pub vote: u64,
+    pub aggregation: u64,
+    pub appeal: u64,
+}
+
+/// The information stored for a court case.
+#[derive(Clone, Debug, Decode, Encode, PartialEq)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, StorageLayout))]
+pub struct CourtInfo {
+    pub status: CourtStatus,
+    pub appeals: Vec<AppealInfo>,
+    pub round_ends: RoundTiming,
+    pub vote_item_type: VoteItemType,
+}
+
+/// The vote of a court participant drawn for a court case.
+#[derive(Clone, Debug, Decode, Encode, PartialEq)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, StorageLayout))]
+pub enum Vote {
+    /// The participant is a delegator and delegated their stake to the listed jurors.
+    Delegated { delegated_stakes: Vec<(AccountId, u128)> },
+    /// The juror was drawn but has not voted yet.
+    Drawn,
+    /// The juror committed a vote.
+    Secret { commitment: CourtHash },
+    /// The juror revealed their commitment vote.
+    Revealed { commitment: CourtHash, vote_item: VoteItem, salt: CourtHash },
+    /// The juror's commitment vote was denounced.
+    Denounced { commitment: CourtHash, vote_item: VoteItem, salt: CourtHash },
+}
+
+/// An account as passed to Zeitgeist's calls, whose `Lookup` is
+/// `AccountIdLookup<AccountId, ()>`. It encodes like `MultiAddress<AccountId, ()>`, and only its
+/// `Id` variant can be resolved, as Zeitgeist has no account indices:
+/// https://github.com/zeitgeistpm/zeitgeist/blob/7ea631dbff5ea519a970c5bc0f3d3d143849d3b9/runtime/common/src/lib.rs#L1222
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```ignore
+/// # use ztg_runtime_lib::primitives::ZtgAddress;
+/// let from_contract: ZtgAddress = self.env().caller().into();
+/// let from_bytes = ZtgAddress::from([0; 32]);
+/// let from_ss58: ZtgAddress = "dE3pPiRvdKqPD5bUDBu3Xpi83McE3Zf3UG8CbhWBQfvUywd7U".parse()?;
+/// ```
+#[derive(Clone, Debug, Decode, Encode, PartialEq, Eq)]
+pub struct ZtgAddress(pub MultiAddress<AccountId, ()>);
+
+impl ZtgAddress {
+    /// Returns the account of an `Id` address.
+    pub fn account_id(&self) -> Option<&AccountId> {
+        match &self.0 {
+            MultiAddress::Id(account_id) => Some(account_id),
+            _ => None,
+        }
+    }
+}
+
+// Not derived, so that the metadata shows the `MultiAddress` the runtime expects.
+#[cfg(feature = "std")]
+impl scale_info::TypeInfo for ZtgAddress {
+    type Identity = <MultiAddress<AccountId, ()> as scale_info::TypeInfo>::Identity;
+
+    fn type_info() -> scale_info::Type {
+        <MultiAddress<AccountId, ()> as scale_info::TypeInfo>::type_info()
+    }
+}
+
+impl From<AccountId> for ZtgAddress {
+    fn from(account_id: AccountId) -> Self {
+        Self(MultiAddress::Id(account_id))
+    }
+}
+
+impl From<[u8; 32]> for ZtgAddress {
+    fn from(bytes: [u8; 32]) -> Self {
+        AccountId::from(bytes).into()
+    }
+}
+
+impl From<MultiAddress<AccountId, ()>> for ZtgAddress {
+    fn from(address: MultiAddress<AccountId, ()>) -> Self {
+        Self(address)
+    }
+}
+
+impl From<ZtgAddress> for MultiAddress<AccountId, ()> {
+    fn from(address: ZtgAddress) -> Self {
+        address.0
+    }
+}
+
+/// Parses a Zeitgeist or Battery Station address, e.g.
+/// `dE3pPiRvdKqPD5bUDBu3Xpi83McE3Zf3UG8CbhWBQfvUywd7U`.
+#[cfg(feature = "std")]
+impl core::str::FromStr for ZtgAddress {
+    type Err = crate::ss58::Ss58Error;
+
+    fn from_str(address: &str) -> Result<Self, Self::Err> {
+        crate::ss58::account_id(address).map(Into::into)
+    }
+}
+
+/// Formats an `Id` address as an SS58 address of the target network.
+#[cfg(feature = "std")]
+impl core::fmt::Display for ZtgAddress {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.account_id() {
+            Some(account_id) => f.write_str(&crate::ss58::to_ss58(account_id)),
+            None => write!(f, "{:?}", self.0),
+        }
+    }
 }
@@ -1,10 +1,11 @@
 use ink::primitives::AccountId;
-use sp_runtime::{MultiAddress, Perbill};
+use sp_runtime::Perbill;
 
 pub type Balance = u128;
 pub type Timestamp = u64;
 pub type BlockNumber = u32;
 
+use crate::network::{Network, Target};
 use crate::primitives::*;
 
 /// The base enum for creating runtime calls in Zeitgeist.  
@@ -13,7 +14,7 @@ use crate::primitives::*;
 ///
 /// Basic usage:
 ///
-/// ```
+/// ```ignore
 /// # use ztg_runtime_lib::{primitives::*, runtime_structs::*};
 /// const result = self.env()
 ///     .call_runtime(&RuntimeCall::AssetManager(AssetManagerCall::Transfer {
@@ -24,55 +25,231 @@ use crate::primitives::*;
 /// ```
 ///
 /// This will call the assetManager.transfer extrinsic.
-#[derive(scale::Encode, scale::Decode)]
+///
+/// The pallet index of each variant is taken from the [`Network`] selected with the `mainnet`,
+/// `battery-station` or `dev` feature, see [`crate::network::Target`].
+#[derive(Clone, Debug, PartialEq)]
 pub enum RuntimeCall {
-    // This index can be found by investigating runtime configuration. You can check the
-    // pallet order inside `construct_runtime!` block and read the position of your
-    // pallet (0-based).
-    //
-    // https://github.com/zeitgeistpm/zeitgeist/blob/7ea631dbff5ea519a970c5bc0f3d3d143849d3b9/runtime/common/src/lib.rs#L274-L330
-    //
-    // [See here for more.](https://substrate.stackexchange.com/questions/778/how-to-get-pallet-index-u8-of-a-pallet-in-runtime)
-    #[codec(index = 40)]
     AssetManager(AssetManagerCall),
-    #[codec(index = 51)]
     Authorized(AuthorizedCall),
-    #[codec(index = 52)]
     Court(CourtCall),
-    #[codec(index = 56)]
     Swaps(SwapsCall),
-    #[codec(index = 57)]
     PredictionMarkets(PredictionMarketsCall),
-    #[codec(index = 58)]
     Styx(StyxCall),
-    #[codec(index = 59)]
     GlobalDisputes(GlobalDisputesCall),
-    #[codec(index = 60)]
     NeoSwaps(NeoSwapsCall),
-    #[codec(index = 61)]
     Orderbook(OrderbookCall),
-    #[codec(index = 62)]
     Parimutuel(ParimutelCall),
+    Utility(UtilityCall),
+    Balances(BalancesCall),
+}
+
+/// Implements the SCALE codec of `RuntimeCall` with the pallet indices of [`crate::network::Target`].
+macro_rules! impl_runtime_call_codec {
+    ($($variant:ident($call:ty) => $index:ident),* $(,)?) => {
+        impl RuntimeCall {
+            /// The name of the network the call is encoded for.
+            pub const NETWORK: &'static str = <Target as Network>::NAME;
+
+            /// Returns the index of the pallet the call is dispatched to.
+            pub fn pallet_index(&self) -> u8 {
+                match self {
+                    $(Self::$variant(_) => <Target as Network>::$index,)*
+                }
+            }
+        }
+
+        $(
+            impl From<$call> for RuntimeCall {
+                fn from(call: $call) -> Self {
+                    Self::$variant(call)
+                }
+            }
+        )*
+
+        impl scale::Encode for RuntimeCall {
+            fn size_hint(&self) -> usize {
+                1 + match self {
+                    $(Self::$variant(call) => call.size_hint(),)*
+                }
+            }
+
+            fn encode_to<T: scale::Output + ?Sized>(&self, dest: &mut T) {
+                dest.push_byte(self.pallet_index());
+                match self {
+                    $(Self::$variant(call) => call.encode_to(dest),)*
+                }
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl scale_info::TypeInfo for RuntimeCall {
+            type Identity = Self;
+
+            fn type_info() -> scale_info::Type {
+                scale_info::Type::builder()
+                    .path(scale_info::Path::new("RuntimeCall", module_path!()))
+                    .variant(scale_info::build::Variants::new()$(
+                        .variant(stringify!($variant), |v| {
+                            v.index(<Target as Network>::$index).fields(
+                                scale_info::build::Fields::unnamed().field(|f| {
+                                    f.ty::<$call>().type_name(stringify!($call))
+                                })
+                            )
+                        })
+                    )*)
+            }
+        }
+
+        impl scale::Decode for RuntimeCall {
+            fn decode<I: scale::Input>(input: &mut I) -> Result<Self, scale::Error> {
+                match input.read_byte()? {
+                    $(
+                        index if index == <Target as Network>::$index => Ok(Self::$variant(
+                            scale::Decode::decode(input).map_err(|e| {
+                                e.chain(concat!("Could not decode `RuntimeCall::", stringify!($variant), "`"))
+                            })?,
+                        )),
+                    )*
+                    _ => Err("Could not decode `RuntimeCall`, variant doesn't exist".into()),
+                }
+            }
+        }
+    };
 }
 
+impl_runtime_call_codec! {
+    AssetManager(AssetManagerCall) => ASSET_MANAGER,
+    Authorized(AuthorizedCall) => AUTHORIZED,
+    Court(CourtCall) => COURT,
+    Swaps(SwapsCall) => SWAPS,
+    PredictionMarkets(PredictionMarketsCall) => PREDICTION_MARKETS,
+    Styx(StyxCall) => STYX,
+    GlobalDisputes(GlobalDisputesCall) => GLOBAL_DISPUTES,
+    NeoSwaps(NeoSwapsCall) => NEO_SWAPS,
+    Orderbook(OrderbookCall) => ORDERBOOK,
+    Parimutuel(ParimutelCall) => PARIMUTUEL,
+    Utility(UtilityCall) => UTILITY,
+    Balances(BalancesCall) => BALANCES,
+}
+
+// Calls are stored as a whole, since fields like `MultiAddress` have no storage layout.
+impl_leaf_layout!(
+    RuntimeCall,
+    AssetManagerCall,
+    AuthorizedCall,
+    CourtCall,
+    SwapsCall,
+    PredictionMarketsCall,
+    StyxCall,
+    GlobalDisputesCall,
+    NeoSwapsCall,
+    OrderbookCall,
+    ParimutelCall,
+    UtilityCall,
+    BalancesCall,
+);
+
 /* ========================== Zeitgeist Pallets ========================== */
 
-#[derive(scale::Encode, scale::Decode)]
+/// Calls for transferring any asset, through orml-currencies.  
+/// https://github.com/open-web3-stack/open-runtime-module-library/tree/22a4f7b7d1066c1a138222f4546d527d32aa4047/currencies
+#[derive(scale::Encode, scale::Decode, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
 pub enum AssetManagerCall {
     /// Transfers an asset from the caller's account to the destination account.
     /// https://github.com/open-web3-stack/open-runtime-module-library/blob/22a4f7b7d1066c1a138222f4546d527d32aa4047/currencies/src/lib.rs#L129-L131C19
     #[codec(index = 0)]
     Transfer {
-        dest: MultiAddress<AccountId, ()>,
+        dest: ZtgAddress,
         currency_id: ZeitgeistAsset,
         #[codec(compact)]
         amount: u128,
     },
+    /// Transfers ZTG from the caller's account to the destination account.
+    /// https://github.com/open-web3-stack/open-runtime-module-library/blob/22a4f7b7d1066c1a138222f4546d527d32aa4047/currencies/src/lib.rs
+    #[codec(index = 1)]
+    TransferNativeCurrency {
+        dest: ZtgAddress,
+        #[codec(compact)]
+        amount: Balance,
+    },
+    /// Mints or burns an asset of an account. Requires root, so it is only useful for dev
+    /// tooling.
+    /// https://github.com/open-web3-stack/open-runtime-module-library/blob/22a4f7b7d1066c1a138222f4546d527d32aa4047/currencies/src/lib.rs
+    #[codec(index = 2)]
+    UpdateBalance {
+        who: ZtgAddress,
+        currency_id: ZeitgeistAsset,
+        amount: i128,
+    },
+}
+
+impl AssetManagerCall {
+    /// Transfers `amount` of an asset to `dest`.
+    pub fn transfer(
+        dest: impl Into<ZtgAddress>,
+        currency_id: ZeitgeistAsset,
+        amount: Balance,
+    ) -> RuntimeCall {
+        RuntimeCall::AssetManager(Self::Transfer { dest: dest.into(), currency_id, amount })
+    }
+
+    /// Transfers `amount` of ZTG to `dest`.
+    pub fn transfer_native_currency(dest: impl Into<ZtgAddress>, amount: Balance) -> RuntimeCall {
+        RuntimeCall::AssetManager(Self::TransferNativeCurrency { dest: dest.into(), amount })
+    }
+
+    /// Sets the balance of `who` to change by `amount`. Requires root.
+    pub fn update_balance(
+        who: impl Into<ZtgAddress>,
+        currency_id: ZeitgeistAsset,
+        amount: i128,
+    ) -> RuntimeCall {
+        RuntimeCall::AssetManager(Self::UpdateBalance { who: who.into(), currency_id, amount })
+    }
+
+    /// Transfers `amount` of an asset to `dest`, but fails if the caller's account would be
+    /// reaped.
+    ///
+    /// Zeitgeist does not expose the calls of orml-tokens, so this is only available for ZTG
+    /// and returns `None` for other assets.
+    pub fn transfer_keep_alive(
+        dest: impl Into<ZtgAddress>,
+        currency_id: ZeitgeistAsset,
+        amount: Balance,
+    ) -> Option<RuntimeCall> {
+        match currency_id {
+            ZeitgeistAsset::Ztg => Some(RuntimeCall::Balances(BalancesCall::TransferKeepAlive {
+                dest: dest.into(),
+                value: amount,
+            })),
+            _ => None,
+        }
+    }
+
+    /// Transfers the whole transferable balance of an asset to `dest`, keeping the existential
+    /// deposit if `keep_alive` is set.
+    ///
+    /// Zeitgeist does not expose the calls of orml-tokens, so this is only available for ZTG
+    /// and returns `None` for other assets.
+    pub fn transfer_all(
+        dest: impl Into<ZtgAddress>,
+        currency_id: ZeitgeistAsset,
+        keep_alive: bool,
+    ) -> Option<RuntimeCall> {
+        match currency_id {
+            ZeitgeistAsset::Ztg => {
+                Some(RuntimeCall::Balances(BalancesCall::TransferAll { dest: dest.into(), keep_alive }))
+            }
+            _ => None,
+        }
+    }
 }
 
 /// Calls for authorizing outcomes.  
 /// https://github.com/zeitgeistpm/zeitgeist/tree/release-v0.5.0/zrml/authorized
-#[derive(scale::Encode, scale::Decode)]
+#[derive(scale::Encode, scale::Decode, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
 pub enum AuthorizedCall {
     /// Overwrites already provided outcomes for the same market and account.
@@ -86,18 +263,22 @@ pub enum AuthorizedCall {
 
 /// Calls for stake-weighted plurality decision making.  
 /// https://github.com/zeitgeistpm/zeitgeist/tree/release-v0.5.0/zrml/court
-#[derive(scale::Encode, scale::Decode)]
+#[derive(scale::Encode, scale::Decode, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
 pub enum CourtCall {
     /// Join to become a juror, who is able to get randomly selected
     /// for court cases according to the provided stake.  
     /// https://github.com/zeitgeistpm/zeitgeist/tree/release-v0.5.0/zrml/court/src/lib.rs#L531
     #[codec(index = 0)]
-    JoinCourt { amount: Balance },
+    JoinCourt {
+        #[codec(compact)]
+        amount: Balance,
+    },
     /// Join the court to become a delegator.  
     /// https://github.com/zeitgeistpm/zeitgeist/tree/release-v0.5.0/zrml/court/src/lib.rs#L565
     #[codec(index = 1)]
     Delegate {
+        #[codec(compact)]
         amount: Balance,
         delegations: ink::prelude::vec::Vec<AccountId>,
     },
@@ -109,7 +290,7 @@ pub enum CourtCall {
     /// https://github.com/zeitgeistpm/zeitgeist/tree/release-v0.5.0/zrml/court/src/lib.rs#L660C16-L660C26
     #[codec(index = 3)]
     ExitCourt {
-        court_participant: MultiAddress<AccountId, u64>,
+        court_participant: ZtgAddress,
     },
     /// Vote as a randomly selected juror for a specific court case.
     /// https://github.com/zeitgeistpm/zeitgeist/tree/release-v0.5.0/zrml/court/src/lib.rs#L717
@@ -125,7 +306,7 @@ pub enum CourtCall {
     DenounceVote {
         #[codec(compact)]
         court_id: CourtId,
-        juror: MultiAddress<AccountId, u64>,
+        juror: ZtgAddress,
         vote_item: VoteItem,
         salt: CourtHash,
     },
@@ -149,6 +330,7 @@ pub enum CourtCall {
     /// https://github.com/zeitgeistpm/zeitgeist/tree/release-v0.5.0/zrml/court/src/lib.rs#L1046
     #[codec(index = 8)]
     ReassignCourtStakes {
+        #[codec(compact)]
         court_id: CourtId,
     },
     /// Set the yearly inflation rate of the court system.
@@ -159,7 +341,7 @@ pub enum CourtCall {
 
 /// Calls for swapping shares out for different ones.  
 /// https://github.com/zeitgeistpm/zeitgeist/tree/release-v0.5.0/zrml/swaps
-#[derive(scale::Encode, scale::Decode)]
+#[derive(scale::Encode, scale::Decode, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
 pub enum SwapsCall {
     /// Exits a pool.
@@ -272,7 +454,7 @@ pub enum SwapsCall {
 
 /// Calls for creating, reporting, and disputing prediction markets.  
 /// https://github.com/zeitgeistpm/zeitgeist/tree/release-v0.5.0/zrml/prediction-markets
-#[derive(scale::Encode, scale::Decode)]
+#[derive(scale::Encode, scale::Decode, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
 pub enum PredictionMarketsCall {
     /// Allows the `CloseOrigin` to immediately move an open market to closed.  
@@ -298,6 +480,7 @@ pub enum PredictionMarketsCall {
     },
     /// Request an edit to a proposed market.  
     /// https://github.com/zeitgeistpm/zeitgeist/tree/release-v0.5.0/zrml/prediction-markets/src/lib.rs#L471
+    #[codec(index = 4)]
     RequestEdit {
         #[codec(compact)]
         market_id: MarketId,
@@ -446,7 +629,7 @@ pub enum PredictionMarketsCall {
 /// Calls for burning native chain tokens in order to gain entry into a registry
 /// for off-chain use.  
 /// https://github.com/zeitgeistpm/zeitgeist/tree/release-v0.5.0/zrml/styx
-#[derive(scale::Encode, scale::Decode)]
+#[derive(scale::Encode, scale::Decode, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
 pub enum StyxCall {
     /// Burns ZTG to cross, granting the ability to claim your zeitgeist avatar. The signer can only cross once.  
@@ -464,7 +647,7 @@ pub enum StyxCall {
 
 /// Calls for setting one out of multiple outcomes with the most locked native tokens as the canonical outcome.  
 /// https://github.com/zeitgeistpm/zeitgeist/tree/release-v0.5.0/zrml/global-disputes
-#[derive(scale::Encode, scale::Decode)]
+#[derive(scale::Encode, scale::Decode, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
 pub enum GlobalDisputesCall {
     /// Add voting outcome to a global dispute in exchange for a constant fee.  
@@ -510,13 +693,13 @@ pub enum GlobalDisputesCall {
     /// https://github.com/zeitgeistpm/zeitgeist/tree/release-v0.5.0/zrml/global-disputes/src/lib.rs#L611
     #[codec(index = 4)]
     UnlockVoteBalance {
-        voter: MultiAddress<AccountId, u64> 
+        voter: ZtgAddress,
     }
 }
 
 /// Calls for interacting with LMSR liquidity pools.  
 /// https://github.com/zeitgeistpm/zeitgeist/tree/release-v0.5.0/zrml/neo-swaps
-#[derive(scale::Encode, scale::Decode)]
+#[derive(scale::Encode, scale::Decode, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
 pub enum NeoSwapsCall {
     /// Buy outcome tokens from the specified market.  
@@ -589,19 +772,21 @@ pub enum NeoSwapsCall {
 /// Calls for interacting with an on-chain order book, which allows to exchange the market's 
 /// base asset for outcome assets and vice versa.  
 /// https://github.com/zeitgeistpm/zeitgeist/tree/release-v0.5.0/zrml/orderbook
-#[derive(scale::Encode, scale::Decode)]
+#[derive(scale::Encode, scale::Decode, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
 pub enum OrderbookCall {
     /// Removes an order.  
     /// https://github.com/zeitgeistpm/zeitgeist/tree/release-v0.5.0/zrml/orderbook/src/lib.rs#L183
     #[codec(index = 0)]
     RemoveOrder {
+        #[codec(compact)]
         order_id: OrderId,
     },
     /// Fills an existing order entirely or partially.  
     /// https://github.com/zeitgeistpm/zeitgeist/tree/release-v0.5.0/zrml/orderbook/src/lib.rs#L205
     #[codec(index = 1)]
     FillOrder {
+        #[codec(compact)]
         order_id: OrderId,
         maker_partial_fill: Option<Balance>
     },
@@ -622,7 +807,7 @@ pub enum OrderbookCall {
 
 /// Calls for interacting with a parimutuel market maker for categorical markets.  
 /// https://github.com/zeitgeistpm/zeitgeist/tree/release-v0.5.0/zrml/parimutuel
-#[derive(scale::Encode, scale::Decode)]
+#[derive(scale::Encode, scale::Decode, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
 pub enum ParimutelCall {
     /// Buy parimutuel shares for the market's base asset.  
@@ -637,6 +822,7 @@ pub enum ParimutelCall {
     /// https://github.com/zeitgeistpm/zeitgeist/blob/release-v0.5.0/zrml/parimutuel/src/lib.rs#L221
     #[codec(index = 1)]
     ClaimRewards {
+        #[codec(compact)]
         market_id: MarketId
     },
     /// Refund the base asset of losing categorical outcome assets.  
@@ -646,3 +832,117 @@ pub enum ParimutelCall {
         refund_asset: ZeitgeistAsset
     },
 }
+
+/* ========================== Substrate Pallets ========================== */
+
+/// Calls for dispatching several calls at once.  
+/// https://github.com/paritytech/polkadot-sdk/blob/release-polkadot-v1.1.0/substrate/frame/utility/src/lib.rs
+#[derive(scale::Encode, scale::Decode, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum UtilityCall {
+    /// Dispatches the calls in order until the first one fails. Calls before the failure are
+    /// not reverted.  
+    /// https://github.com/paritytech/polkadot-sdk/blob/release-polkadot-v1.1.0/substrate/frame/utility/src/lib.rs
+    #[codec(index = 0)]
+    Batch {
+        calls: ink::prelude::vec::Vec<RuntimeCall>,
+    },
+    /// Dispatches the calls in order and reverts all of them if one fails.  
+    /// https://github.com/paritytech/polkadot-sdk/blob/release-polkadot-v1.1.0/substrate/frame/utility/src/lib.rs
+    #[codec(index = 2)]
+    BatchAll {
+        calls: ink::prelude::vec::Vec<RuntimeCall>,
+    },
+    /// Dispatches every call, also after one of them failed. Failed calls are reverted
+    /// individually.  
+    /// https://github.com/paritytech/polkadot-sdk/blob/release-polkadot-v1.1.0/substrate/frame/utility/src/lib.rs
+    #[codec(index = 4)]
+    ForceBatch {
+        calls: ink::prelude::vec::Vec<RuntimeCall>,
+    },
+}
+
+/// Calls for transferring native ZTG.  
+/// https://github.com/paritytech/polkadot-sdk/blob/release-polkadot-v1.1.0/substrate/frame/balances/src/lib.rs
+#[derive(scale::Encode, scale::Decode, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum BalancesCall {
+    /// Transfers ZTG to another account. The caller's account is reaped if its balance falls
+    /// below the existential deposit.  
+    /// https://github.com/paritytech/polkadot-sdk/blob/release-polkadot-v1.1.0/substrate/frame/balances/src/lib.rs
+    #[codec(index = 0)]
+    TransferAllowDeath {
+        dest: ZtgAddress,
+        #[codec(compact)]
+        value: Balance,
+    },
+    /// Transfers ZTG to another account, but fails if the caller's account would be reaped.  
+    /// https://github.com/paritytech/polkadot-sdk/blob/release-polkadot-v1.1.0/substrate/frame/balances/src/lib.rs
+    #[codec(index = 3)]
+    TransferKeepAlive {
+        dest: ZtgAddress,
+        #[codec(compact)]
+        value: Balance,
+    },
+    /// Transfers the whole transferable balance. Keeps the existential deposit in the caller's
+    /// account if `keep_alive` is set.  
+    /// https://github.com/paritytech/polkadot-sdk/blob/release-polkadot-v1.1.0/substrate/frame/balances/src/lib.rs
+    #[codec(index = 4)]
+    TransferAll {
+        dest: ZtgAddress,
+        keep_alive: bool,
+    },
+}
+
+/// Collects calls into a single [`UtilityCall`], so they can be dispatched with one
+/// `call_runtime`.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```ignore
+/// # use ztg_runtime_lib::{primitives::*, runtime_structs::*};
+/// let call = Batch::new()
+///     .call(PredictionMarketsCall::BuyCompleteSet { market_id, amount })
+///     .call(OrderbookCall::PlaceOrder { market_id, maker_asset, maker_amount, taker_asset, taker_amount })
+///     .batch_all();
+/// self.env().call_runtime(&call)?;
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Batch {
+    calls: ink::prelude::vec::Vec<RuntimeCall>,
+}
+
+impl Batch {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a call to the batch.
+    pub fn call(mut self, call: impl Into<RuntimeCall>) -> Self {
+        self.calls.push(call.into());
+        self
+    }
+
+    /// Dispatches the calls until the first failure, see [`UtilityCall::Batch`].
+    pub fn batch(self) -> RuntimeCall {
+        RuntimeCall::Utility(UtilityCall::Batch { calls: self.calls })
+    }
+
+    /// Dispatches all calls or none of them, see [`UtilityCall::BatchAll`].
+    pub fn batch_all(self) -> RuntimeCall {
+        RuntimeCall::Utility(UtilityCall::BatchAll { calls: self.calls })
+    }
+
+    /// Dispatches every call regardless of failures, see [`UtilityCall::ForceBatch`].
+    pub fn force_batch(self) -> RuntimeCall {
+        RuntimeCall::Utility(UtilityCall::ForceBatch { calls: self.calls })
+    }
+}
+
+impl Extend<RuntimeCall> for Batch {
+    fn extend<T: IntoIterator<Item = RuntimeCall>>(&mut self, calls: T) {
+        self.calls.extend(calls);
+    }
+}
@@ -0,0 +1,87 @@
+//! SS58 addresses of Zeitgeist accounts.
+//!
+//! Zeitgeist registered the SS58 prefix 73, whose addresses start with `dE`. Battery Station
+//! and development nodes use the generic prefix 42, whose addresses start with `5`:
+//! https://github.com/paritytech/ss58-registry/blob/main/ss58-registry.json
+//!
+//! The encoding and the checksum are those of sp-core's `Ss58Codec`, this module only restricts
+//! the prefixes to Zeitgeist's.
+
+use core::fmt;
+use ink::primitives::AccountId;
+use sp_core::crypto::{AccountId32, PublicError, Ss58AddressFormat, Ss58Codec};
+
+use crate::network::{Network, Target};
+
+/// The prefix of Zeitgeist addresses, e.g. `dE3pPiRvdKqPD5bUDBu3Xpi83McE3Zf3UG8CbhWBQfvUywd7U`.
+pub const ZEITGEIST_PREFIX: u16 = 73;
+/// The generic prefix used by Battery Station, e.g.
+/// `5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY`.
+pub const GENERIC_PREFIX: u16 = 42;
+
+/// The reasons a string is not the SS58 address of a Zeitgeist account.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Ss58Error {
+    InvalidBase58,
+    /// The address does not hold a 32 byte account, e.g. a truncated address.
+    InvalidLength,
+    /// The checksum does not match, e.g. because of a typo.
+    InvalidChecksum,
+    /// The address does not start with an SS58 prefix.
+    InvalidPrefix,
+    /// The address is valid, but of a network other than Zeitgeist and Battery Station.
+    UnsupportedPrefix(u16),
+}
+
+impl fmt::Display for Ss58Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidBase58 => f.write_str("address is not base58"),
+            Self::InvalidLength => f.write_str("address does not hold a 32 byte account"),
+            Self::InvalidChecksum => f.write_str("address has an invalid checksum"),
+            Self::InvalidPrefix => f.write_str("address has an invalid prefix"),
+            Self::UnsupportedPrefix(prefix) => {
+                write!(f, "address of network {prefix} is not a Zeitgeist address")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Ss58Error {}
+
+impl From<PublicError> for Ss58Error {
+    fn from(error: PublicError) -> Self {
+        match error {
+            PublicError::BadBase58 => Self::InvalidBase58,
+            PublicError::BadLength => Self::InvalidLength,
+            PublicError::InvalidChecksum => Self::InvalidChecksum,
+            _ => Self::InvalidPrefix,
+        }
+    }
+}
+
+/// Encodes `account_id` as an address of the network with `prefix`.
+pub fn encode(account_id: &AccountId, prefix: u16) -> String {
+    AccountId32::from(*AsRef::<[u8; 32]>::as_ref(account_id))
+        .to_ss58check_with_version(Ss58AddressFormat::custom(prefix))
+}
+
+/// Encodes `account_id` as an address of the [`Target`] network.
+pub fn to_ss58(account_id: &AccountId) -> String {
+    encode(account_id, Target::SS58_PREFIX)
+}
+
+/// Decodes a Zeitgeist or Battery Station address into its account and prefix.
+pub fn decode(address: &str) -> Result<(AccountId, u16), Ss58Error> {
+    let (account_id, format) = AccountId32::from_ss58check_with_version(address)?;
+    let prefix = format.prefix();
+    if prefix != ZEITGEIST_PREFIX && prefix != GENERIC_PREFIX {
+        return Err(Ss58Error::UnsupportedPrefix(prefix));
+    }
+    Ok((AccountId::from(<[u8; 32]>::from(account_id)), prefix))
+}
+
+/// Decodes a Zeitgeist or Battery Station address into its account.
+pub fn account_id(address: &str) -> Result<AccountId, Ss58Error> {
+    decode(address).map(|(account_id, _)| account_id)
+}
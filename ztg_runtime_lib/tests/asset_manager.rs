@@ -0,0 +1,45 @@
+use ink::primitives::AccountId;
+use scale::Encode;
+use ztg_runtime_lib::{primitives::ZeitgeistAsset, runtime_structs::*};
+
+const BOB: [u8; 32] = [2; 32];
+// `MultiAddress::Id` of Bob.
+const BOB_ADDRESS: &str = "000202020202020202020202020202020202020202020202020202020202020202";
+
+fn encoded(call: RuntimeCall) -> String {
+    hex::encode(call.encode())
+}
+
+#[test]
+fn encodes_currency_calls() {
+    let bob = AccountId::from(BOB);
+
+    assert_eq!(
+        encoded(AssetManagerCall::transfer(bob, ZeitgeistAsset::ForeignAsset(1), 4)),
+        format!("2800{BOB_ADDRESS}050100000010")
+    );
+    assert_eq!(
+        encoded(AssetManagerCall::transfer_native_currency(bob, 4)),
+        format!("2801{BOB_ADDRESS}10")
+    );
+    assert_eq!(
+        encoded(AssetManagerCall::update_balance(bob, ZeitgeistAsset::Ztg, -5)),
+        format!("2802{BOB_ADDRESS}04fbffffffffffffffffffffffffffffff")
+    );
+}
+
+#[test]
+fn keeps_ztg_transfers_alive_through_balances() {
+    let bob = AccountId::from(BOB);
+
+    assert_eq!(
+        AssetManagerCall::transfer_keep_alive(bob, ZeitgeistAsset::Ztg, 4),
+        Some(RuntimeCall::Balances(BalancesCall::TransferKeepAlive { dest: bob.into(), value: 4 }))
+    );
+    assert_eq!(
+        AssetManagerCall::transfer_all(bob, ZeitgeistAsset::Ztg, true),
+        Some(RuntimeCall::Balances(BalancesCall::TransferAll { dest: bob.into(), keep_alive: true }))
+    );
+    assert_eq!(AssetManagerCall::transfer_keep_alive(bob, ZeitgeistAsset::ForeignAsset(1), 4), None);
+    assert_eq!(AssetManagerCall::transfer_all(bob, ZeitgeistAsset::PoolShare(0), false), None);
+}
@@ -0,0 +1,84 @@
+use scale::{Decode, Encode};
+use ztg_runtime_lib::{primitives::*, runtime_structs::*};
+
+const ZTG: u128 = 10_000_000_000;
+
+fn assert_encodes_to<T: Encode>(value: &T, hex: &str) {
+    assert_eq!(hex::encode(value.encode()), hex);
+}
+
+#[test]
+fn scalar_outcome_encodes_position() {
+    let long = ZeitgeistAsset::ScalarOutcome(3, ScalarPosition::Long);
+    assert_encodes_to(&long, "010300000000000000000000000000000000");
+    assert_encodes_to(
+        &ZeitgeistAsset::ScalarOutcome(3, ScalarPosition::Short),
+        "010300000000000000000000000000000001",
+    );
+    assert_eq!(
+        ZeitgeistAsset::decode(&mut &long.encode()[..]).unwrap(),
+        long
+    );
+}
+
+#[test]
+fn pool_share_encodes_pool_id() {
+    let pool_share = ZeitgeistAsset::PoolShare(5);
+    assert_encodes_to(&pool_share, "0305000000000000000000000000000000");
+    assert_eq!(
+        ZeitgeistAsset::decode(&mut &pool_share.encode()[..]).unwrap(),
+        pool_share
+    );
+}
+
+#[test]
+fn neo_swaps_buy_scalar_outcome() {
+    assert_encodes_to(
+        &RuntimeCall::NeoSwaps(NeoSwapsCall::Buy {
+            market_id: 3,
+            asset_count: 2,
+            asset_out: ZeitgeistAsset::ScalarOutcome(3, ScalarPosition::Short),
+            amount_in: 10 * ZTG,
+            min_amount_out: 5 * ZTG,
+        }),
+        "3c000c02000103000000000000000000000000000000010700e87648170700743ba40b",
+    );
+}
+
+#[test]
+fn orderbook_place_order_scalar_outcome() {
+    assert_encodes_to(
+        &RuntimeCall::Orderbook(OrderbookCall::PlaceOrder {
+            market_id: 3,
+            maker_asset: ZeitgeistAsset::ScalarOutcome(3, ScalarPosition::Long),
+            maker_amount: 2 * ZTG,
+            taker_asset: ZeitgeistAsset::Ztg,
+            taker_amount: ZTG,
+        }),
+        "3d020c0103000000000000000000000000000000000700c817a804040700e40b5402",
+    );
+}
+
+#[test]
+fn swaps_with_scalar_outcome_and_pool_share() {
+    assert_encodes_to(
+        &RuntimeCall::Swaps(SwapsCall::SwapExactAmountIn {
+            pool_id: 1,
+            asset_in: ZeitgeistAsset::Ztg,
+            asset_amount_in: ZTG,
+            asset_out: ZeitgeistAsset::ScalarOutcome(3, ScalarPosition::Short),
+            min_asset_amount_out: Some(1),
+            max_price: None,
+        }),
+        "380904040700e40b5402010300000000000000000000000000000001010100000000000000000000000000000000",
+    );
+    assert_encodes_to(
+        &RuntimeCall::Swaps(SwapsCall::PoolExitWithExactPoolAmount {
+            pool_id: 1,
+            asset: ZeitgeistAsset::PoolShare(1),
+            pool_amount: ZTG,
+            min_asset_amount: 0,
+        }),
+        "38040403010000000000000000000000000000000700e40b540200",
+    );
+}
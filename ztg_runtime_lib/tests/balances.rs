@@ -0,0 +1,29 @@
+use ink::primitives::AccountId;
+use scale::Encode;
+use ztg_runtime_lib::runtime_structs::*;
+
+const BOB: [u8; 32] = [2; 32];
+
+fn assert_encodes_to(call: BalancesCall, expected: &str) {
+    assert_eq!(hex::encode(RuntimeCall::Balances(call).encode()), expected);
+}
+
+#[test]
+fn encodes_transfers() {
+    let bob = || AccountId::from(BOB).into();
+    let account = "000202020202020202020202020202020202020202020202020202020202020202";
+
+    // Balances, call index, `MultiAddress::Id`, compact value.
+    assert_encodes_to(
+        BalancesCall::TransferAllowDeath { dest: bob(), value: 10_000_000_000 },
+        &format!("0a00{account}0700e40b5402"),
+    );
+    assert_encodes_to(
+        BalancesCall::TransferKeepAlive { dest: bob(), value: 1 },
+        &format!("0a03{account}04"),
+    );
+    assert_encodes_to(
+        BalancesCall::TransferAll { dest: bob(), keep_alive: true },
+        &format!("0a04{account}01"),
+    );
+}
@@ -0,0 +1,103 @@
+use ink::{primitives::AccountId, ChainExtensionInstance};
+use sp_runtime::Perbill;
+use ztg_runtime_lib::{
+    chain_extension::{mock::MockZeitgeistExtension, ZeitgeistExtension},
+    errors::{PredictionMarketsError, ZeitgeistError},
+    primitives::*,
+    runtime_structs::*,
+};
+
+fn queries() -> <ZeitgeistExtension as ChainExtensionInstance>::Instance {
+    <ZeitgeistExtension as ChainExtensionInstance>::instantiate()
+}
+
+fn market(status: MarketStatus) -> Market {
+    let oracle = AccountId::from([1; 32]);
+    Market {
+        base_asset: ZeitgeistAsset::Ztg,
+        creator: oracle,
+        creation: MarketCreation::Permissionless,
+        creator_fee: Perbill::zero(),
+        oracle,
+        metadata: vec![0; 50],
+        market_type: MarketType::Categorical(2),
+        period: MarketPeriod::Block(100..200),
+        deadlines: Deadlines { grace_period: 0, oracle_duration: 300, dispute_duration: 3_600 },
+        scoring_rule: ScoringRule::Lmsr,
+        status,
+        report: None,
+        resolved_outcome: None,
+        dispute_mechanism: Some(MarketDisputeMechanism::Court),
+        bonds: MarketBonds::default(),
+        early_close: None,
+    }
+}
+
+#[ink::test]
+fn mock_answers_market_queries() {
+    let extension = MockZeitgeistExtension::default();
+    extension.state().markets.insert(3, market(MarketStatus::Closed));
+    extension.register();
+
+    assert_eq!(queries().market_status(3), Some(MarketStatus::Closed));
+    assert_eq!(queries().market_report(3), None);
+    assert_eq!(queries().market_status(4), None);
+
+    // State changes after registration are visible to the registered functions.
+    let report = Report { at: 250, by: AccountId::from([1; 32]), outcome: OutcomeReport::Categorical(1) };
+    extension.state().markets.insert(
+        3,
+        Market {
+            report: Some(report.clone()),
+            resolved_outcome: Some(OutcomeReport::Categorical(1)),
+            ..market(MarketStatus::Resolved)
+        },
+    );
+    // Every query answers from the same market.
+    assert_eq!(queries().market(3).map(|m| m.status), Some(MarketStatus::Resolved));
+    assert_eq!(queries().market_status(3), Some(MarketStatus::Resolved));
+    assert_eq!(queries().market_report(3), Some(report));
+    assert_eq!(
+        queries().market_resolved_outcome(3),
+        Some(OutcomeReport::Categorical(1))
+    );
+}
+
+#[ink::test]
+fn mock_answers_court_queries() {
+    let extension = MockZeitgeistExtension::default();
+    let juror = ink::primitives::AccountId::from([1; 32]);
+    extension.state().court_ids.insert(7, 2);
+    extension
+        .state()
+        .court_votes
+        .insert((2, juror), Vote::Secret { commitment: [9; 32] });
+    extension.register();
+
+    assert_eq!(queries().court_id_of_market(7), Some(2));
+    assert_eq!(
+        queries().court_vote(2, juror),
+        Some(Vote::Secret { commitment: [9; 32] })
+    );
+    assert_eq!(queries().court(2), None);
+}
+
+#[ink::test]
+fn mock_records_dispatched_calls() {
+    let extension = MockZeitgeistExtension::default();
+    extension.register();
+    let redeem = || RuntimeCall::PredictionMarkets(PredictionMarketsCall::RedeemShares { market_id: 7 });
+
+    assert_eq!(queries().dispatch(redeem()), None);
+
+    let error = ZeitgeistError::PredictionMarkets(PredictionMarketsError::MarketIsNotResolved);
+    extension.state().dispatch_error = Some(error);
+    assert_eq!(queries().dispatch(redeem()), Some(error));
+
+    let dispatched = &extension.state().dispatched;
+    assert_eq!(dispatched.len(), 2);
+    assert!(matches!(
+        dispatched[1],
+        RuntimeCall::PredictionMarkets(PredictionMarketsCall::RedeemShares { market_id: 7 })
+    ));
+}
@@ -0,0 +1,116 @@
+use scale::{Compact, Decode};
+use sp_core::{ed25519, sr25519, Pair};
+use sp_runtime::{traits::Verify, MultiSignature};
+use ztg_runtime_lib::{client::*, primitives::ZeitgeistAsset, runtime_structs::*};
+
+fn redeem_shares() -> RuntimeCall {
+    RuntimeCall::PredictionMarkets(PredictionMarketsCall::RedeemShares { market_id: 7 })
+}
+
+#[test]
+fn signs_immortal_extrinsic() {
+    // ed25519 signatures are deterministic, so the whole extrinsic can be compared.
+    let pair = ed25519::Pair::from_seed(&[1; 32]);
+    let params = ExtrinsicParams::new(50, 24, [0x11; 32]).nonce(5);
+
+    assert_eq!(
+        hex::encode(signer_payload(&redeem_shares(), &params)),
+        concat!(
+            "390c1c",   // call
+            "00",       // immortal era
+            "14",       // nonce
+            "00",       // tip
+            "00",       // fee asset
+            "32000000", // spec version
+            "18000000", // transaction version
+            "1111111111111111111111111111111111111111111111111111111111111111",
+            "1111111111111111111111111111111111111111111111111111111111111111",
+        )
+    );
+    assert_eq!(
+        hex::encode(sign(&redeem_shares(), &params, &pair)),
+        concat!(
+            "a901",
+            "84",
+            "00",
+            "8a88e3dd7409f195fd52db2d3cba5d72ca6709bf1d94121bf3748801b40f6f5c",
+            "00",
+            "4ed63c84a635772b220d3ca8106a53cd1c83d6c1a0a24f1f0832e4e5b557282d",
+            "7f888ad482027c26110cf9b85dc251ddd420710b00d715a0e38a058451405c0d",
+            "00140000",
+            "390c1c",
+        )
+    );
+}
+
+#[test]
+fn signs_mortal_extrinsic() {
+    let pair = sr25519::Pair::from_string("//Alice", None).unwrap();
+    let params = ExtrinsicParams::new(50, 24, [0x11; 32])
+        .nonce(1)
+        .tip(1000)
+        .fee_asset(ZeitgeistAsset::ForeignAsset(0))
+        .mortal(64, 1000, [0x22; 32]);
+    let extrinsic = sign(&redeem_shares(), &params, &pair);
+
+    let input = &mut &extrinsic[..];
+    let length = Compact::<u32>::decode(input).unwrap().0 as usize;
+    assert_eq!(length, input.len());
+    assert_eq!(hex::encode(&input[..34]), format!("8400{}", hex::encode(pair.public())));
+    *input = &input[34..];
+
+    // sr25519 signatures are randomized, so only check that the signature is valid.
+    let signature = MultiSignature::decode(input).unwrap();
+    let payload = signer_payload(&redeem_shares(), &params);
+    assert!(signature.verify(&payload[..], &pair.public().into()));
+
+    assert_eq!(
+        hex::encode(input),
+        concat!(
+            "8502",       // era of 64 blocks starting at block 1000
+            "04",         // nonce
+            "a10f",       // tip
+            "010500000000", // fee asset
+            "390c1c",
+        )
+    );
+}
+
+/// Signed with subxt-core 0.38 from Zeitgeist's signed extensions, an ed25519-dalek signer with
+/// the same seed and the call bytes written by hand.
+#[test]
+fn matches_subxt_extrinsic() {
+    let pair = ed25519::Pair::from_seed(&[1; 32]);
+    let params =
+        ExtrinsicParams::new(50, 24, [0x11; 32]).nonce(1).tip(1000).mortal(64, 1000, [0x22; 32]);
+
+    assert_eq!(
+        hex::encode(signer_payload(&redeem_shares(), &params)),
+        concat!(
+            "390c1c850204a10f0032000000180000001111111111111111111111111111111111111111111111111111",
+            "1111111111112222222222222222222222222222222222222222222222222222222222222222",
+        )
+    );
+    assert_eq!(
+        hex::encode(sign(&redeem_shares(), &params, &pair)),
+        concat!(
+            "b10184008a88e3dd7409f195fd52db2d3cba5d72ca6709bf1d94121bf3748801b40f6f5c00d0e871dd1cc1",
+            "436062abdf00cc78675ac010654b450c5c2dffe2546286aa831399b5b95ba39d913c713c6e1e4abb7add99",
+            "a7cb855c2872311f8a26174f34c70b850204a10f00390c1c",
+        )
+    );
+}
+
+#[test]
+fn mortal_eras_start_at_their_birth() {
+    // Periods up to 4096 start at the given block.
+    assert_eq!(era_birth(64, 1001), 1001);
+    assert_eq!(era_birth(4096, 1001), 1001);
+    // Longer periods are quantized to multiples of `period / 4096`.
+    assert_eq!(era_birth(8192, 1001), 1000);
+    assert_eq!(era_birth(65536, 1001), 992);
+
+    let params = ExtrinsicParams::new(50, 24, [0x11; 32]).mortal(8192, 1001, [0x22; 32]);
+    assert_eq!(params.era.birth(1001), 1000);
+    assert_eq!(params.era_checkpoint, [0x22; 32]);
+}
@@ -0,0 +1,18 @@
+use ztg_runtime_lib::metadata::RuntimeCalls;
+
+/// Reads the runtime metadata checked in at `metadata/zeitgeist.scale`, or the file in
+/// `ZTG_METADATA` if it is set. Fails if there is none, so the checks against the runtime are
+/// never skipped.
+pub fn runtime() -> RuntimeCalls {
+    let path = std::env::var_os("ZTG_METADATA")
+        .map(std::path::PathBuf::from)
+        .unwrap_or_else(|| concat!(env!("CARGO_MANIFEST_DIR"), "/metadata/zeitgeist.scale").into());
+    let bytes = std::fs::read(&path).unwrap_or_else(|e| {
+        panic!(
+            "no runtime metadata at {}: {e}. Fetch it with `state_getMetadata` as described in \
+             `ztg_runtime_lib::metadata`.",
+            path.display()
+        )
+    });
+    RuntimeCalls::decode(&bytes).expect("metadata file is valid")
+}
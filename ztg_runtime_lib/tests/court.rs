@@ -0,0 +1,77 @@
+use ink::primitives::AccountId;
+use scale::Encode;
+use ztg_runtime_lib::{court::*, primitives::*, runtime_structs::*};
+
+const JUROR: [u8; 32] = [1; 32];
+const SALT: CourtHash = [7; 32];
+
+fn hex_commitment(vote_item: VoteItem) -> String {
+    hex::encode(commitment(&AccountId::from(JUROR), &vote_item, &SALT))
+}
+
+#[test]
+fn computes_court_commitments() {
+    // blake2b-256 of the encoded `(juror, vote_item, salt)`.
+    assert_eq!(
+        hex_commitment(VoteItem::Outcome(OutcomeReport::Categorical(1))),
+        "3a9028bad9451f07832092c75281af17f94e222a75ff08dec32d266b92e72775"
+    );
+    assert_eq!(
+        hex_commitment(VoteItem::Outcome(OutcomeReport::Scalar(25))),
+        "12ad03aac0d5f682839e2b38430898ed06b00d82f51ada04bce07d93a8102dda"
+    );
+    assert_eq!(
+        hex_commitment(VoteItem::Binary(true)),
+        "6eb86316981676bc304e86a3835a793c1c110f8a4d206564dbf51179276ffd71"
+    );
+}
+
+/// zrml-court checks a reveal against `T::Hashing::hash_of(&(who, vote_item, salt))`. The
+/// preimage below is written out byte by byte and hashed with Python's
+/// `hashlib.blake2b(preimage, digest_size=32)`, independently of the SCALE codec.
+#[test]
+fn matches_runtime_hashing() {
+    let preimage = [
+        &[1; 32][..],  // juror
+        &[0, 0, 3, 0], // VoteItem::Outcome(OutcomeReport::Categorical(3u16))
+        &[7; 32],      // salt
+    ]
+    .concat();
+    let vote_item = VoteItem::Outcome(OutcomeReport::Categorical(3));
+    assert_eq!((AccountId::from(JUROR), &vote_item, SALT).encode(), preimage);
+    assert_eq!(
+        hex_commitment(vote_item),
+        "130dafcf61b6d86741c9df87c67a5f1a426c30ad80327358b8eba31033b88976"
+    );
+}
+
+#[test]
+fn derives_salts_per_court() {
+    let secret = [9; 32];
+    assert_eq!(
+        hex::encode(derive_salt(&secret, 3)),
+        "fc5986c1e06ec1530694aea2858df638399ffaf9c87a32e215a281344781aeef"
+    );
+    assert_ne!(derive_salt(&secret, 3), derive_salt(&secret, 4));
+    assert_ne!(derive_salt(&secret, 3), derive_salt(&[8; 32], 3));
+}
+
+#[test]
+fn builds_vote_and_reveal_calls() {
+    let juror = AccountId::from(JUROR);
+    let vote = SecretVote { vote_item: VoteItem::Binary(true), salt: SALT };
+
+    assert_eq!(
+        vote.vote(2, &juror),
+        RuntimeCall::Court(CourtCall::Vote { court_id: 2, commitment_vote: vote.commitment(&juror) })
+    );
+    assert_eq!(
+        vote.reveal(2),
+        RuntimeCall::Court(CourtCall::RevealVote {
+            court_id: 2,
+            vote_item: VoteItem::Binary(true),
+            salt: SALT,
+        })
+    );
+    assert_eq!(SecretVote::new(VoteItem::Binary(true), &[9; 32], 2).salt, derive_salt(&[9; 32], 2));
+}
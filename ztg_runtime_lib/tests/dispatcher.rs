@@ -0,0 +1,85 @@
+use ink::env::DefaultEnvironment;
+use ztg_runtime_lib::{
+    assert_dispatched,
+    chain_extension::mock::MockZeitgeistExtension,
+    errors::{PredictionMarketsError, ZeitgeistError},
+    harness::*,
+    primitives::*,
+    runtime_structs::*,
+};
+
+/// Contract logic that takes its dispatcher as an argument.
+fn buy_and_redeem(
+    dispatcher: &mut impl ZeitgeistDispatcher,
+    market_id: MarketId,
+) -> Result<(), ZeitgeistError> {
+    dispatcher.dispatch(&RuntimeCall::NeoSwaps(NeoSwapsCall::Buy {
+        market_id,
+        asset_count: 2,
+        asset_out: ZeitgeistAsset::CategoricalOutcome(market_id, 0),
+        amount_in: 100,
+        min_amount_out: 150,
+    }))?;
+    dispatcher.dispatch(&RuntimeCall::PredictionMarkets(PredictionMarketsCall::RedeemShares {
+        market_id,
+    }))
+}
+
+#[ink::test]
+fn records_calls() {
+    let mut dispatcher = RecordingDispatcher::new();
+    buy_and_redeem(&mut dispatcher, 3).unwrap();
+
+    assert_dispatched!(dispatcher, RuntimeCall::NeoSwaps(NeoSwapsCall::Buy { market_id: 3, .. }));
+    assert_dispatched!(
+        dispatcher,
+        RuntimeCall::NeoSwaps(NeoSwapsCall::Buy { amount_in, min_amount_out, .. })
+            if min_amount_out > amount_in
+    );
+    assert_eq!(
+        dispatcher.last_call(),
+        Some(&RuntimeCall::PredictionMarkets(PredictionMarketsCall::RedeemShares { market_id: 3 }))
+    );
+    assert_eq!(dispatcher.calls().len(), 2);
+    dispatcher.clear();
+    assert!(dispatcher.calls().is_empty());
+}
+
+#[ink::test]
+#[should_panic(expected = "no call matching")]
+fn fails_on_calls_that_were_not_dispatched() {
+    let mut dispatcher = RecordingDispatcher::new();
+    buy_and_redeem(&mut dispatcher, 3).unwrap();
+    assert_dispatched!(dispatcher, RuntimeCall::NeoSwaps(NeoSwapsCall::Buy { market_id: 4, .. }));
+}
+
+#[ink::test]
+fn returns_queued_responses() {
+    let mut dispatcher = RecordingDispatcher::new();
+    dispatcher.respond(Err(ZeitgeistError::CallRuntimeFailed));
+    assert_eq!(buy_and_redeem(&mut dispatcher, 3), Err(ZeitgeistError::CallRuntimeFailed));
+    // The redemption was never dispatched.
+    assert_eq!(dispatcher.calls().len(), 1);
+    assert_eq!(buy_and_redeem(&mut dispatcher, 3), Ok(()));
+}
+
+#[ink::test]
+fn dispatches_with_call_runtime() {
+    let runtime = RuntimeHarness::install();
+    runtime.respond(Ok(()));
+    runtime.respond(Err(ZeitgeistError::CallRuntimeFailed));
+    let mut dispatcher = RuntimeDispatcher::<DefaultEnvironment>::new();
+    assert_eq!(buy_and_redeem(&mut dispatcher, 3), Err(ZeitgeistError::CallRuntimeFailed));
+    assert_dispatched!(runtime, RuntimeCall::PredictionMarkets(PredictionMarketsCall::RedeemShares { .. }));
+}
+
+#[ink::test]
+fn dispatches_with_the_chain_extension() {
+    let extension = MockZeitgeistExtension::default();
+    extension.register();
+    let error = ZeitgeistError::PredictionMarkets(PredictionMarketsError::MarketIsNotResolved);
+    extension.state().dispatch_error = Some(error);
+
+    assert_eq!(buy_and_redeem(&mut ExtensionDispatcher, 3), Err(error));
+    assert_eq!(extension.state().dispatched.len(), 1);
+}
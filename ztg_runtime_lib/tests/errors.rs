@@ -0,0 +1,75 @@
+mod common;
+
+use sp_runtime::{DispatchError, ModuleError};
+use ztg_runtime_lib::{
+    errors::*,
+    network::{Network, Target},
+};
+
+fn module_error(index: u8, error: u8) -> DispatchError {
+    DispatchError::Module(ModuleError { index, error: [error, 0, 0, 0], message: None })
+}
+
+#[test]
+fn maps_module_errors_onto_pallets() {
+    assert_eq!(
+        ZeitgeistError::from(module_error(<Target as Network>::PREDICTION_MARKETS, 12)),
+        ZeitgeistError::PredictionMarkets(PredictionMarketsError::MarketIsNotActive)
+    );
+    assert_eq!(
+        ZeitgeistError::from(module_error(<Target as Network>::COURT, 28)),
+        ZeitgeistError::Court(CourtError::NotDrawnJuror)
+    );
+    assert_eq!(
+        ZeitgeistError::from(module_error(<Target as Network>::SWAPS, 6)),
+        ZeitgeistError::Swaps(SwapsError::InsufficientBalance)
+    );
+}
+
+#[test]
+fn keeps_unknown_errors() {
+    // An error index past the end of the pallet's errors.
+    assert_eq!(
+        ZeitgeistError::from(module_error(<Target as Network>::STYX, 200)),
+        ZeitgeistError::Module { index: <Target as Network>::STYX, error: 200 }
+    );
+    // A pallet that is not known to the library.
+    assert_eq!(
+        ZeitgeistError::from(module_error(0, 1)),
+        ZeitgeistError::Module { index: 0, error: 1 }
+    );
+    assert_eq!(ZeitgeistError::from(DispatchError::BadOrigin), ZeitgeistError::Dispatch);
+}
+
+#[test]
+fn maps_environment_errors() {
+    assert_eq!(
+        ZeitgeistError::from(ink::env::Error::CallRuntimeFailed),
+        ZeitgeistError::CallRuntimeFailed
+    );
+    assert_eq!(
+        ZeitgeistError::from(ink::env::Error::CalleeTrapped),
+        ZeitgeistError::Environment
+    );
+}
+
+/// `from_module_error` maps errors by index, so every error has to be at its index in the
+/// runtime.
+#[test]
+fn pallet_errors_match_metadata_file() {
+    let mismatches = common::runtime().verify_errors();
+    let report: Vec<String> = mismatches.iter().map(ToString::to_string).collect();
+    assert!(report.is_empty(), "{}", report.join("\n"));
+}
+
+#[test]
+fn maps_balances_and_utility_errors() {
+    assert_eq!(
+        ZeitgeistError::from(module_error(<Target as Network>::BALANCES, 2)),
+        ZeitgeistError::Balances(BalancesError::InsufficientBalance)
+    );
+    assert_eq!(
+        ZeitgeistError::from(module_error(<Target as Network>::UTILITY, 0)),
+        ZeitgeistError::Utility(UtilityError::TooManyCalls)
+    );
+}
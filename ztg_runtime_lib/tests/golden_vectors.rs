@@ -0,0 +1,469 @@
+//! Checks every call variant against its golden vector in `fixtures/calls.txt`.
+//!
+//! The values follow the sampling rules of `ztg_runtime_lib::golden`: numbers count up from 1
+//! in field order, arrays repeat one value and enums take their first variant with fields.
+
+mod common;
+
+use ink::primitives::AccountId;
+use scale::{Decode, Encode};
+use sp_runtime::Perbill;
+use std::collections::BTreeMap;
+use ztg_runtime_lib::{golden, metadata::RuntimeCalls, primitives::*, runtime_structs::*};
+
+const FIXTURES: &str = include_str!("fixtures/calls.txt");
+
+fn account(n: u8) -> AccountId {
+    AccountId::from([n; 32])
+}
+
+fn asset(market_id: MarketId, index: u16) -> ZeitgeistAsset {
+    ZeitgeistAsset::CategoricalOutcome(market_id, index)
+}
+
+fn deadlines() -> Deadlines {
+    Deadlines { grace_period: 7, oracle_duration: 8, dispute_duration: 9 }
+}
+
+fn table() -> Vec<(&'static str, RuntimeCall)> {
+    use RuntimeCall::*;
+    vec![
+        (
+            "AssetManager::transfer",
+            AssetManager(AssetManagerCall::Transfer {
+                dest: account(1).into(),
+                currency_id: asset(2, 3),
+                amount: 4,
+            }),
+        ),
+        (
+            "AssetManager::transfer_native_currency",
+            AssetManager(AssetManagerCall::TransferNativeCurrency { dest: account(1).into(), amount: 2 }),
+        ),
+        (
+            "AssetManager::update_balance",
+            AssetManager(AssetManagerCall::UpdateBalance {
+                who: account(1).into(),
+                currency_id: asset(2, 3),
+                amount: 4,
+            }),
+        ),
+        (
+            "Authorized::authorize_market_outcome",
+            Authorized(AuthorizedCall::AuthorizeMarketOutcome {
+                market_id: 1,
+                outcome: OutcomeReport::Categorical(2),
+            }),
+        ),
+        ("Court::join_court", Court(CourtCall::JoinCourt { amount: 1 })),
+        ("Court::delegate", Court(CourtCall::Delegate { amount: 1, delegations: vec![account(2)] })),
+        ("Court::prepare_exit_court", Court(CourtCall::PrepareExitCourt)),
+        ("Court::exit_court", Court(CourtCall::ExitCourt { court_participant: account(1).into() })),
+        ("Court::vote", Court(CourtCall::Vote { court_id: 1, commitment_vote: [2; 32] })),
+        (
+            "Court::denounce_vote",
+            Court(CourtCall::DenounceVote {
+                court_id: 1,
+                juror: account(2).into(),
+                vote_item: VoteItem::Outcome(OutcomeReport::Categorical(3)),
+                salt: [4; 32],
+            }),
+        ),
+        (
+            "Court::reveal_vote",
+            Court(CourtCall::RevealVote {
+                court_id: 1,
+                vote_item: VoteItem::Outcome(OutcomeReport::Categorical(2)),
+                salt: [3; 32],
+            }),
+        ),
+        ("Court::appeal", Court(CourtCall::Appeal { court_id: 1 })),
+        ("Court::reassign_court_stakes", Court(CourtCall::ReassignCourtStakes { court_id: 1 })),
+        ("Court::set_inflation", Court(CourtCall::SetInflation { inflation: Perbill::from_parts(1) })),
+        (
+            "Swaps::pool_exit",
+            Swaps(SwapsCall::PoolExit { pool_id: 1, pool_amount: 2, min_assets_out: vec![3] }),
+        ),
+        (
+            "Swaps::pool_exit_with_exact_asset_amount",
+            Swaps(SwapsCall::PoolExitWithExactAssetAmount {
+                pool_id: 1,
+                asset: asset(2, 3),
+                asset_amount: 4,
+                max_pool_amount: 5,
+            }),
+        ),
+        (
+            "Swaps::pool_exit_with_exact_pool_amount",
+            Swaps(SwapsCall::PoolExitWithExactPoolAmount {
+                pool_id: 1,
+                asset: asset(2, 3),
+                pool_amount: 4,
+                min_asset_amount: 5,
+            }),
+        ),
+        (
+            "Swaps::pool_join",
+            Swaps(SwapsCall::PoolJoin { pool_id: 1, pool_amount: 2, max_assets_in: vec![3] }),
+        ),
+        (
+            "Swaps::pool_join_with_exact_asset_amount",
+            Swaps(SwapsCall::PoolJoinWithExactAssetAmount {
+                pool_id: 1,
+                asset_in: asset(2, 3),
+                asset_amount: 4,
+                min_pool_amount: 5,
+            }),
+        ),
+        (
+            "Swaps::pool_join_with_exact_pool_amount",
+            Swaps(SwapsCall::PoolJoinWithExactPoolAmount {
+                pool_id: 1,
+                asset: asset(2, 3),
+                pool_amount: 4,
+                max_asset_amount: 5,
+            }),
+        ),
+        (
+            "Swaps::swap_exact_amount_in",
+            Swaps(SwapsCall::SwapExactAmountIn {
+                pool_id: 1,
+                asset_in: asset(2, 3),
+                asset_amount_in: 4,
+                asset_out: asset(5, 6),
+                min_asset_amount_out: Some(7),
+                max_price: Some(8),
+            }),
+        ),
+        (
+            "Swaps::swap_exact_amount_out",
+            Swaps(SwapsCall::SwapExactAmountOut {
+                pool_id: 1,
+                asset_in: asset(2, 3),
+                max_asset_amount_in: Some(4),
+                asset_out: asset(5, 6),
+                asset_amount_out: 7,
+                max_price: Some(8),
+            }),
+        ),
+        (
+            "Swaps::force_pool_exit",
+            Swaps(SwapsCall::ForcePoolExit {
+                who: account(1),
+                pool_id: 2,
+                pool_amount: 3,
+                min_assets_out: vec![4],
+            }),
+        ),
+        (
+            "PredictionMarkets::admin_move_market_to_closed",
+            PredictionMarkets(PredictionMarketsCall::AdminMoveMarketToClosed { market_id: 1 }),
+        ),
+        (
+            "PredictionMarkets::admin_move_market_to_resolved",
+            PredictionMarkets(PredictionMarketsCall::AdminMoveMarketToResolved { market_id: 1 }),
+        ),
+        (
+            "PredictionMarkets::approve_market",
+            PredictionMarkets(PredictionMarketsCall::ApproveMarket { market_id: 1 }),
+        ),
+        (
+            "PredictionMarkets::request_edit",
+            PredictionMarkets(PredictionMarketsCall::RequestEdit { market_id: 1, edit_reason: vec![2] }),
+        ),
+        (
+            "PredictionMarkets::buy_complete_set",
+            PredictionMarkets(PredictionMarketsCall::BuyCompleteSet { market_id: 1, amount: 2 }),
+        ),
+        ("PredictionMarkets::dispute", PredictionMarkets(PredictionMarketsCall::Dispute { market_id: 1 })),
+        (
+            "PredictionMarkets::create_market",
+            PredictionMarkets(PredictionMarketsCall::CreateMarket {
+                base_asset: asset(1, 2),
+                creator_fee: Perbill::from_parts(3),
+                oracle: account(4),
+                period: MarketPeriod::Block(5..6),
+                deadlines: deadlines(),
+                metadata: MultiHash::Sha3_384([10; 50]),
+                creation: MarketCreation::Permissionless,
+                market_type: MarketType::Categorical(11),
+                dispute_mechanism: Some(MarketDisputeMechanism::Authorized),
+                scoring_rule: ScoringRule::Lmsr,
+            }),
+        ),
+        (
+            "PredictionMarkets::edit_market",
+            PredictionMarkets(PredictionMarketsCall::EditMarket {
+                base_asset: asset(1, 2),
+                market_id: 3,
+                oracle: account(4),
+                period: MarketPeriod::Block(5..6),
+                deadlines: deadlines(),
+                metadata: MultiHash::Sha3_384([10; 50]),
+                market_type: MarketType::Categorical(11),
+                dispute_mechanism: Some(MarketDisputeMechanism::Authorized),
+                scoring_rule: ScoringRule::Lmsr,
+            }),
+        ),
+        (
+            "PredictionMarkets::redeem_shares",
+            PredictionMarkets(PredictionMarketsCall::RedeemShares { market_id: 1 }),
+        ),
+        (
+            "PredictionMarkets::reject_market",
+            PredictionMarkets(PredictionMarketsCall::RejectMarket { market_id: 1, reject_reason: vec![2] }),
+        ),
+        (
+            "PredictionMarkets::report",
+            PredictionMarkets(PredictionMarketsCall::Report {
+                market_id: 1,
+                outcome: OutcomeReport::Categorical(2),
+            }),
+        ),
+        (
+            "PredictionMarkets::sell_complete_set",
+            PredictionMarkets(PredictionMarketsCall::SellCompleteSet { market_id: 1, amount: 2 }),
+        ),
+        (
+            "PredictionMarkets::start_global_dispute",
+            PredictionMarkets(PredictionMarketsCall::StartGlobalDispute { market_id: 1 }),
+        ),
+        (
+            "PredictionMarkets::create_market_and_deploy_pool",
+            PredictionMarkets(PredictionMarketsCall::CreateMarketAndDeployPool {
+                base_asset: asset(1, 2),
+                creator_fee: Perbill::from_parts(3),
+                oracle: account(4),
+                period: MarketPeriod::Block(5..6),
+                deadlines: deadlines(),
+                metadata: MultiHash::Sha3_384([10; 50]),
+                market_type: MarketType::Categorical(11),
+                dispute_mechanism: Some(MarketDisputeMechanism::Authorized),
+                amount: 12,
+                spot_prices: vec![13],
+                swap_fee: 14,
+            }),
+        ),
+        (
+            "PredictionMarkets::schedule_early_close",
+            PredictionMarkets(PredictionMarketsCall::ScheduleEarlyClose { market_id: 1 }),
+        ),
+        (
+            "PredictionMarkets::dispute_early_close",
+            PredictionMarkets(PredictionMarketsCall::DisputeEarlyClose { market_id: 1 }),
+        ),
+        (
+            "PredictionMarkets::reject_early_close",
+            PredictionMarkets(PredictionMarketsCall::RejectEarlyClose { market_id: 1 }),
+        ),
+        (
+            "PredictionMarkets::close_trusted_market",
+            PredictionMarkets(PredictionMarketsCall::CloseTrustedMarket { market_id: 1 }),
+        ),
+        (
+            "PredictionMarkets::manually_close_market",
+            PredictionMarkets(PredictionMarketsCall::ManuallyCloseMarket { market_id: 1 }),
+        ),
+        ("Styx::cross", Styx(StyxCall::Cross)),
+        ("Styx::set_burn_amount", Styx(StyxCall::SetBurnAmount { amount: 1 })),
+        (
+            "GlobalDisputes::add_vote_outcome",
+            GlobalDisputes(GlobalDisputesCall::AddVoteOutcome {
+                market_id: 1,
+                outcome: OutcomeReport::Categorical(2),
+            }),
+        ),
+        (
+            "GlobalDisputes::refund_vote_fees",
+            GlobalDisputes(GlobalDisputesCall::RefundVoteFees { market_id: 1 }),
+        ),
+        (
+            "GlobalDisputes::purge_outcomes",
+            GlobalDisputes(GlobalDisputesCall::PurgeOutcomes { market_id: 1 }),
+        ),
+        (
+            "GlobalDisputes::reward_outcome_owner",
+            GlobalDisputes(GlobalDisputesCall::RewardOutcomeOwner { market_id: 1 }),
+        ),
+        (
+            "GlobalDisputes::vote_on_outcome",
+            GlobalDisputes(GlobalDisputesCall::VoteOnOutcome {
+                market_id: 1,
+                outcome: OutcomeReport::Categorical(2),
+                amount: 3,
+            }),
+        ),
+        (
+            "GlobalDisputes::unlock_vote_balance",
+            GlobalDisputes(GlobalDisputesCall::UnlockVoteBalance { voter: account(1).into() }),
+        ),
+        (
+            "NeoSwaps::buy",
+            NeoSwaps(NeoSwapsCall::Buy {
+                market_id: 1,
+                asset_count: 2,
+                asset_out: asset(3, 4),
+                amount_in: 5,
+                min_amount_out: 6,
+            }),
+        ),
+        (
+            "NeoSwaps::sell",
+            NeoSwaps(NeoSwapsCall::Sell {
+                market_id: 1,
+                asset_count: 2,
+                asset_in: asset(3, 4),
+                amount_in: 5,
+                min_amount_out: 6,
+            }),
+        ),
+        (
+            "NeoSwaps::join",
+            NeoSwaps(NeoSwapsCall::Join { market_id: 1, pool_shares_amount: 2, max_amounts_in: vec![3] }),
+        ),
+        (
+            "NeoSwaps::exit",
+            NeoSwaps(NeoSwapsCall::Exit {
+                market_id: 1,
+                pool_shares_amount_out: 2,
+                min_amounts_out: vec![3],
+            }),
+        ),
+        ("NeoSwaps::withdraw_fees", NeoSwaps(NeoSwapsCall::WithdrawFees { market_id: 1 })),
+        (
+            "NeoSwaps::deploy_pool",
+            NeoSwaps(NeoSwapsCall::DeployPool { market_id: 1, amount: 2, spot_prices: vec![3], swap_fee: 4 }),
+        ),
+        ("Orderbook::remove_order", Orderbook(OrderbookCall::RemoveOrder { order_id: 1 })),
+        (
+            "Orderbook::fill_order",
+            Orderbook(OrderbookCall::FillOrder { order_id: 1, maker_partial_fill: Some(2) }),
+        ),
+        (
+            "Orderbook::place_order",
+            Orderbook(OrderbookCall::PlaceOrder {
+                market_id: 1,
+                maker_asset: asset(2, 3),
+                maker_amount: 4,
+                taker_asset: asset(5, 6),
+                taker_amount: 7,
+            }),
+        ),
+        ("Parimutuel::buy", Parimutuel(ParimutelCall::Buy { asset: asset(1, 2), amount: 3 })),
+        ("Parimutuel::claim_rewards", Parimutuel(ParimutelCall::ClaimRewards { market_id: 1 })),
+        (
+            "Parimutuel::claim_refunds",
+            Parimutuel(ParimutelCall::ClaimRefunds { refund_asset: asset(1, 2) }),
+        ),
+        ("Utility::batch", Utility(UtilityCall::Batch { calls: vec![] })),
+        ("Utility::batch_all", Utility(UtilityCall::BatchAll { calls: vec![] })),
+        ("Utility::force_batch", Utility(UtilityCall::ForceBatch { calls: vec![] })),
+        (
+            "Balances::transfer_allow_death",
+            Balances(BalancesCall::TransferAllowDeath { dest: account(1).into(), value: 2 }),
+        ),
+        (
+            "Balances::transfer_keep_alive",
+            Balances(BalancesCall::TransferKeepAlive { dest: account(1).into(), value: 2 }),
+        ),
+        (
+            "Balances::transfer_all",
+            Balances(BalancesCall::TransferAll { dest: account(1).into(), keep_alive: true }),
+        ),
+    ]
+}
+
+fn fixtures() -> BTreeMap<&'static str, Vec<u8>> {
+    FIXTURES
+        .lines()
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let (name, encoded) = line.split_once(' ').expect("fixture lines are `<name> <hex>`");
+            (name, hex::decode(encoded).expect("fixtures are hex"))
+        })
+        .collect()
+}
+
+#[test]
+fn every_call_has_a_golden_vector() {
+    let vectors = golden::generate(&RuntimeCalls::library()).unwrap();
+    let names: Vec<String> = vectors.iter().map(|v| v.name()).collect();
+    let table: Vec<&str> = table().iter().map(|(name, _)| *name).collect();
+    let fixtures: Vec<&str> = fixtures().into_keys().collect();
+
+    assert_eq!(table, names, "the table covers every call in declaration order");
+    let mut sorted = table.clone();
+    sorted.sort();
+    assert_eq!(fixtures, sorted, "the fixtures cover every call");
+}
+
+#[test]
+fn calls_encode_to_their_golden_vector() {
+    let fixtures = fixtures();
+    let mismatches: Vec<String> = table()
+        .into_iter()
+        .filter_map(|(name, call)| {
+            let (encoded, golden) = (call.encode(), fixtures.get(name)?);
+            (&encoded != golden).then(|| {
+                format!("{name}: encodes to {}, golden is {}", hex::encode(encoded), hex::encode(golden))
+            })
+        })
+        .collect();
+    assert!(mismatches.is_empty(), "{}", mismatches.join("\n"));
+}
+
+#[test]
+fn golden_vectors_decode_to_their_call() {
+    let fixtures = fixtures();
+    for (name, call) in table() {
+        let golden = &fixtures[name];
+        assert_eq!(RuntimeCall::decode(&mut &golden[..]), Ok(call), "{name}");
+    }
+}
+
+/// Ids and amounts that the pallets declare `#[pallet::compact]`, with values that take more than
+/// one byte. The vectors were encoded by hand.
+#[test]
+fn encodes_compact_fields() {
+    use RuntimeCall::*;
+    let vectors = [
+        (
+            Court(CourtCall::Delegate { amount: 100 * 10_000_000_000, delegations: vec![account(2)] }),
+            "3401070010a5d4e8040202020202020202020202020202020202020202020202020202020202020202",
+        ),
+        (Court(CourtCall::ReassignCourtStakes { court_id: 1000 }), "3408a10f"),
+        (Orderbook(OrderbookCall::RemoveOrder { order_id: 1000 }), "3d00a10f"),
+        (
+            Orderbook(OrderbookCall::FillOrder { order_id: 70_000, maker_partial_fill: None }),
+            "3d01c245040000",
+        ),
+        (Parimutuel(ParimutelCall::ClaimRewards { market_id: 42 }), "3e01a8"),
+    ];
+    for (call, expected) in vectors {
+        assert_eq!(hex::encode(call.encode()), expected, "{call:?}");
+    }
+}
+
+/// Fails if the fixtures were not regenerated from the checked-in runtime metadata, e.g. after
+/// a runtime upgrade.
+#[test]
+fn fixtures_match_the_runtime() {
+    let vectors = golden::generate(&common::runtime()).unwrap();
+    assert_eq!(golden::to_fixtures(&vectors, "runtime metadata zeitgeist.scale"), FIXTURES);
+}
+
+#[test]
+fn shows_runtime_drift() {
+    let mut runtime = RuntimeCalls::library();
+    runtime.pallets.iter_mut().find(|(name, ..)| name == "Styx").unwrap().1 = 3;
+    let vectors = golden::generate(&runtime).unwrap();
+    let cross = vectors.iter().find(|v| v.name() == "Styx::cross").unwrap();
+    assert_eq!(hex::encode(&cross.encoded), "0300");
+    assert_ne!(golden::to_fixtures(&vectors, "runtime metadata zeitgeist.scale"), FIXTURES);
+
+    runtime.pallets.retain(|(name, ..)| name != "Orderbook");
+    assert_eq!(
+        golden::generate(&runtime),
+        Err(golden::GoldenError::MissingPallet { pallet: "Orderbook".into() })
+    );
+}
@@ -0,0 +1,74 @@
+use ink::env::DefaultEnvironment;
+use ztg_runtime_lib::{errors::ZeitgeistError, harness::*, primitives::MarketId, runtime_structs::*};
+
+fn redeem_shares(market_id: MarketId) -> RuntimeCall {
+    RuntimeCall::PredictionMarkets(PredictionMarketsCall::RedeemShares { market_id })
+}
+
+fn dispatch(market_id: MarketId) -> Result<(), ZeitgeistError> {
+    call_runtime::<DefaultEnvironment>(&redeem_shares(market_id))
+}
+
+#[test]
+fn captures_calls_until_dropped() {
+    let runtime = RuntimeHarness::install();
+    dispatch(1).unwrap();
+    dispatch(2).unwrap();
+    assert_eq!(runtime.calls(), vec![redeem_shares(1), redeem_shares(2)]);
+    drop(runtime);
+
+    let runtime = RuntimeHarness::install();
+    assert!(runtime.calls().is_empty());
+}
+
+#[test]
+#[should_panic(expected = "already installed")]
+fn installs_one_harness_per_thread() {
+    let _runtime = RuntimeHarness::install();
+    RuntimeHarness::install();
+}
+
+#[test]
+fn isolates_threads() {
+    let runtime = RuntimeHarness::install();
+    std::thread::spawn(|| {
+        let runtime = RuntimeHarness::install();
+        dispatch(1).unwrap();
+        assert_eq!(runtime.calls().len(), 1);
+    })
+    .join()
+    .unwrap();
+    assert!(runtime.calls().is_empty());
+}
+
+#[test]
+fn scripts_responses() {
+    let runtime = RuntimeHarness::install();
+    runtime.respond(Err(ZeitgeistError::CallRuntimeFailed));
+    runtime.respond(Ok(()));
+    runtime.handler(|call| match call {
+        RuntimeCall::PredictionMarkets(PredictionMarketsCall::RedeemShares { market_id: 0 }) => {
+            Err(ZeitgeistError::Dispatch)
+        }
+        _ => Ok(()),
+    });
+
+    assert_eq!(dispatch(0), Err(ZeitgeistError::CallRuntimeFailed));
+    assert_eq!(dispatch(0), Ok(()));
+    assert_eq!(dispatch(0), Err(ZeitgeistError::Dispatch));
+    assert_eq!(dispatch(1), Ok(()));
+    assert_eq!(runtime.calls().len(), 4);
+}
+
+#[test]
+fn lets_handlers_dispatch_calls() {
+    let runtime = RuntimeHarness::install();
+    runtime.handler(|call| match call {
+        RuntimeCall::PredictionMarkets(PredictionMarketsCall::RedeemShares { market_id: 0 }) => {
+            dispatch(1)
+        }
+        _ => Ok(()),
+    });
+    dispatch(0).unwrap();
+    assert_eq!(runtime.calls(), vec![redeem_shares(0), redeem_shares(1)]);
+}
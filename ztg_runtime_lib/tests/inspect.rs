@@ -0,0 +1,89 @@
+use scale::Encode;
+use ztg_runtime_lib::{
+    inspect::*,
+    network::{Network, Target},
+    primitives::*,
+    runtime_structs::*,
+    ss58,
+};
+
+#[test]
+fn inspects_swap() {
+    // The call of the polkadot.js link in `SwapsCall`.
+    let call = inspect_hex(
+        "0x380981040402286bee00b102000000000000000000000000000001000100cdbe7b00000000000000000000000000",
+    )
+    .unwrap();
+
+    assert_eq!(call.pallet, "Swaps");
+    assert_eq!(call.call, "swap_exact_amount_in");
+    assert_eq!(
+        call.to_string(),
+        [
+            "Swaps.swap_exact_amount_in",
+            "  pool_id: 288",
+            "  asset_in: Ztg",
+            "  asset_amount_in: 0.1",
+            "  asset_out: CategoricalOutcome(689, 1)",
+            "  min_asset_amount_out: Some(0.2076101888)",
+            "  max_price: None",
+        ]
+        .join("\n")
+    );
+}
+
+#[test]
+fn inspects_encoded_calls() {
+    let call = RuntimeCall::PredictionMarkets(PredictionMarketsCall::Report {
+        market_id: 3,
+        outcome: OutcomeReport::Scalar(25),
+    });
+    let info = inspect(&call.encode()).unwrap();
+
+    assert_eq!(info.call, "report");
+    assert_eq!(
+        info.args,
+        vec![
+            ("market_id".into(), Value::Uint(3)),
+            ("outcome".into(), Value::Variant("Scalar".into(), vec![(None, Value::Uint(25))])),
+        ]
+    );
+}
+
+#[test]
+fn rejects_invalid_call_data() {
+    assert!(inspect_hex("0x3801").is_err());
+    assert!(inspect_hex("0x38zz").is_err());
+    // A valid call followed by another byte.
+    assert!(inspect_hex("0x390c1c00").is_err());
+}
+
+#[test]
+fn formats_amounts_in_ztg() {
+    assert_eq!(format_amount(0), "0");
+    assert_eq!(format_amount(1), "0.0000000001");
+    assert_eq!(format_amount(15_000_000_000), "1.5");
+    assert_eq!(format_amount(120_000_000_000), "12");
+}
+
+#[test]
+fn formats_accounts_as_addresses() {
+    let call = AssetManagerCall::transfer_native_currency(
+        "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY".parse::<ZtgAddress>().unwrap(),
+        15_000_000_000,
+    );
+    // Addresses are printed with the prefix of the network selected with the network features.
+    let alice = match Target::SS58_PREFIX {
+        ss58::ZEITGEIST_PREFIX => "dE3pPiRvdKqPD5bUDBu3Xpi83McE3Zf3UG8CbhWBQfvUywd7U",
+        _ => "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY",
+    };
+    assert_eq!(
+        inspect(&call.encode()).unwrap().to_string(),
+        [
+            "AssetManager.transfer_native_currency".to_string(),
+            format!("  dest: Id({alice})"),
+            "  amount: 1.5".to_string(),
+        ]
+        .join("\n")
+    );
+}
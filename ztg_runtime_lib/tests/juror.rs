@@ -0,0 +1,62 @@
+use ink::primitives::AccountId;
+use ztg_runtime_lib::{court::SecretVote, juror::*, primitives::*, runtime_structs::*};
+
+const CONTRACT: [u8; 32] = [5; 32];
+
+fn secret_vote() -> SecretVote {
+    SecretVote { vote_item: VoteItem::Outcome(OutcomeReport::Categorical(0)), salt: [3; 32] }
+}
+
+#[test]
+fn walks_through_a_court_case() {
+    let contract = AccountId::from(CONTRACT);
+    let mut agent = JurorAgent::new(100);
+
+    assert_eq!(agent.join(10).unwrap(), RuntimeCall::Court(CourtCall::JoinCourt { amount: 10 }));
+    assert_eq!(agent.participation(), Participation::Joined);
+    assert_eq!(agent.join(10), Err(JurorAgentError::StakeNotIncreased));
+    assert!(agent.join(20).is_ok());
+    assert_eq!(agent.stake(), 20);
+
+    assert_eq!(agent.reveal(1), Err(JurorAgentError::NotDrawn));
+    agent.drawn(1).unwrap();
+    assert_eq!(agent.drawn(1), Err(JurorAgentError::AlreadyDrawn));
+    assert_eq!(agent.reveal(1), Err(JurorAgentError::NotCommitted));
+
+    assert_eq!(agent.vote(1, secret_vote(), &contract).unwrap(), secret_vote().vote(1, &contract));
+    assert_eq!(agent.case(1), Some(&CaseVote::Committed(secret_vote())));
+    assert_eq!(agent.vote(1, secret_vote(), &contract), Err(JurorAgentError::AlreadyCommitted));
+
+    assert_eq!(agent.reveal(1).unwrap(), secret_vote().reveal(1));
+    assert_eq!(agent.case(1), Some(&CaseVote::Revealed(secret_vote())));
+    assert_eq!(agent.reveal(1), Err(JurorAgentError::NotCommitted));
+
+    agent.close(1).unwrap();
+    assert_eq!(agent.case(1), None);
+}
+
+#[test]
+fn locks_exit_until_unlock_block() {
+    let contract = AccountId::from(CONTRACT);
+    let mut agent = JurorAgent::new(100);
+
+    assert_eq!(agent.prepare_exit(5), Err(JurorAgentError::NotJoined));
+    assert_eq!(agent.exit(5, contract), Err(JurorAgentError::NotJoined));
+    agent.join(10).unwrap();
+    agent.drawn(2).unwrap();
+    assert_eq!(agent.exit(5, contract), Err(JurorAgentError::NotExiting));
+    assert_eq!(agent.prepare_exit(5).unwrap(), RuntimeCall::Court(CourtCall::PrepareExitCourt));
+    assert_eq!(agent.participation(), Participation::Exiting { unlock_at: 105 });
+    assert_eq!(agent.prepare_exit(6), Err(JurorAgentError::Exiting));
+    assert_eq!(agent.join(20), Err(JurorAgentError::Exiting));
+
+    assert_eq!(agent.exit(104, contract), Err(JurorAgentError::ExitLocked { unlock_at: 105 }));
+    assert_eq!(agent.exit(105, contract), Err(JurorAgentError::CasesPending));
+    agent.close(2).unwrap();
+    assert_eq!(
+        agent.exit(105, contract).unwrap(),
+        RuntimeCall::Court(CourtCall::ExitCourt { court_participant: contract.into() })
+    );
+    assert_eq!(agent.participation(), Participation::NotJoined);
+    assert_eq!(agent.stake(), 0);
+}
@@ -0,0 +1,93 @@
+use ink::{
+    metadata::layout::Layout,
+    primitives::Key,
+    storage::traits::StorageLayout,
+};
+use scale_info::{meta_type, PortableRegistry, Registry, TypeDef, TypeInfo};
+use ztg_runtime_lib::{primitives::*, runtime_structs::*};
+
+fn is_leaf<T: StorageLayout>() -> bool {
+    matches!(T::layout(&Key::default()), Layout::Leaf(_))
+}
+
+/// Registers `T` and returns its path and the names of its variants, or of its fields.
+fn registered<T: TypeInfo + 'static>() -> (String, Vec<String>) {
+    let mut registry = Registry::new();
+    let id = registry.register_type(&meta_type::<T>()).id;
+    let registry: PortableRegistry = registry.into();
+    let ty = registry.resolve(id).expect("registered type");
+    let names = match &ty.type_def {
+        TypeDef::Variant(def) => def.variants.iter().map(|v| v.name.clone()).collect(),
+        TypeDef::Composite(def) => def.fields.iter().filter_map(|f| f.name.clone()).collect(),
+        _ => Vec::new(),
+    };
+    (ty.path.segments.join("::"), names)
+}
+
+#[test]
+fn calls_can_be_stored() {
+    assert!(is_leaf::<RuntimeCall>());
+    assert!(is_leaf::<AssetManagerCall>());
+    assert!(is_leaf::<AuthorizedCall>());
+    assert!(is_leaf::<CourtCall>());
+    assert!(is_leaf::<SwapsCall>());
+    assert!(is_leaf::<PredictionMarketsCall>());
+    assert!(is_leaf::<StyxCall>());
+    assert!(is_leaf::<GlobalDisputesCall>());
+    assert!(is_leaf::<NeoSwapsCall>());
+    assert!(is_leaf::<OrderbookCall>());
+    assert!(is_leaf::<ParimutelCall>());
+    // Vectors of calls, e.g. a queue of calls to dispatch, are stored in one cell.
+    assert!(is_leaf::<ink::prelude::vec::Vec<RuntimeCall>>());
+}
+
+#[test]
+fn primitives_can_be_stored() {
+    assert!(is_leaf::<MarketPeriod>());
+    assert!(is_leaf::<MarketType>());
+    assert!(is_leaf::<MultiHash>());
+    assert!(is_leaf::<Market>());
+    assert!(matches!(ZeitgeistAsset::layout(&Key::default()), Layout::Enum(_)));
+    assert!(matches!(Order::layout(&Key::default()), Layout::Struct(_)));
+    assert!(matches!(NeoSwapsPool::layout(&Key::default()), Layout::Struct(_)));
+    assert!(matches!(EarlyClose::layout(&Key::default()), Layout::Struct(_)));
+}
+
+#[test]
+fn calls_have_type_info() {
+    let (path, pallets) = registered::<RuntimeCall>();
+    assert_eq!(path, "ztg_runtime_lib::runtime_structs::RuntimeCall");
+    assert_eq!(
+        pallets,
+        [
+            "AssetManager",
+            "Authorized",
+            "Court",
+            "Swaps",
+            "PredictionMarkets",
+            "Styx",
+            "GlobalDisputes",
+            "NeoSwaps",
+            "Orderbook",
+            "Parimutuel",
+            "Utility",
+            "Balances",
+        ]
+    );
+
+    let (path, calls) = registered::<AssetManagerCall>();
+    assert_eq!(path, "ztg_runtime_lib::runtime_structs::AssetManagerCall");
+    assert_eq!(calls[..2], ["Transfer", "TransferNativeCurrency"]);
+
+    let (path, variants) = registered::<MarketPeriod>();
+    assert_eq!(path, "ztg_runtime_lib::primitives::MarketPeriod");
+    assert_eq!(variants, ["Block", "Timestamp"]);
+
+    let (path, variants) = registered::<MarketType>();
+    assert_eq!(path, "ztg_runtime_lib::primitives::MarketType");
+    assert_eq!(variants, ["Categorical", "Scalar"]);
+
+    let (path, variants) = registered::<MultiHash>();
+    assert_eq!(path, "ztg_runtime_lib::primitives::MultiHash");
+    assert_eq!(variants, ["Sha3_384"]);
+}
@@ -0,0 +1,189 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use ztg_runtime_lib::{errors::NeoSwapsError, lmsr::*, runtime_structs::Balance};
+
+const CASES: usize = 500;
+/// The liquidity parameter at which a reserve of 10 has a spot price of 1/2, `10 / ln(2)`.
+const LIQUIDITY: Balance = 144_269_504_088;
+
+fn to_f64(balance: Balance) -> f64 {
+    balance as f64 / BASE as f64
+}
+
+fn buy_reference(reserve: Balance, amount_in: Balance, liquidity: Balance) -> f64 {
+    let (r, x, b) = (to_f64(reserve), to_f64(amount_in), to_f64(liquidity));
+    b * ((x / b).exp_m1() + (-r / b).exp()).ln() + r - x
+}
+
+fn sell_reference(reserve: Balance, amount_in: Balance, liquidity: Balance) -> f64 {
+    let (r, x, b) = (to_f64(reserve), to_f64(amount_in), to_f64(liquidity));
+    -b * ((-(x + r) / b).exp() - (-r / b).exp_m1()).ln()
+}
+
+/// Asserts that `actual` is within the documented deviation of the exact value.
+fn assert_close(actual: Balance, expected: f64) {
+    let tolerance = max_deviation(actual) as f64 / BASE as f64;
+    assert!(
+        (to_f64(actual) - expected).abs() <= tolerance,
+        "{actual} is not close to {expected}"
+    );
+}
+
+/// A random pool of two assets, as reserve of the traded asset, reserve of the other asset and
+/// liquidity parameter.
+fn random_pool(rng: &mut StdRng) -> (Balance, Balance, Balance) {
+    let liquidity = rng.gen_range(BASE..10_000 * BASE);
+    let price = rng.gen_range(0.02..0.98f64);
+    let reserve = |p: f64| (-to_f64(liquidity) * f64::ln(p) * BASE as f64) as Balance;
+    (reserve(price), reserve(1.0 - price), liquidity)
+}
+
+#[test]
+fn matches_pallet_test_vectors() {
+    // The test cases of zrml-neo-swaps' math.
+    let close = |actual: Balance, expected: Balance| {
+        assert!(actual.abs_diff(expected) <= max_deviation(expected), "{actual} != {expected}");
+    };
+    close(calculate_swap_amount_out_for_buy(10 * BASE, 10 * BASE, LIQUIDITY).unwrap(), 58_496_250_072);
+    close(calculate_swap_amount_out_for_sell(10 * BASE, 10 * BASE, LIQUIDITY).unwrap(), 41_503_749_928);
+    close(calculate_spot_price(10 * BASE, LIQUIDITY).unwrap(), BASE / 2);
+    close(calculate_reserve_from_spot_price(LIQUIDITY, BASE / 2).unwrap(), 10 * BASE);
+    close(calculate_liquidity_parameter(10 * BASE, BASE / 2).unwrap(), LIQUIDITY);
+}
+
+#[test]
+fn buy_matches_reference() {
+    let mut rng = StdRng::seed_from_u64(1);
+    for _ in 0..CASES {
+        let (reserve, _, liquidity) = random_pool(&mut rng);
+        let amount_in = rng.gen_range(1..5 * liquidity);
+        let amount_out = calculate_swap_amount_out_for_buy(reserve, amount_in, liquidity).unwrap();
+        assert_close(amount_out, buy_reference(reserve, amount_in, liquidity));
+        assert!(amount_out <= reserve);
+    }
+}
+
+/// Small buys from a pool with a high reserve take the logarithm of a number below one, whose
+/// rounding must not raise the amount out.
+#[test]
+fn buy_rounds_in_favor_of_the_pool() {
+    let mut rng = StdRng::seed_from_u64(4);
+    for _ in 0..CASES {
+        let (reserve, _, liquidity) = random_pool(&mut rng);
+        let amount_in = rng.gen_range(1..liquidity / 100);
+        let amount_out = calculate_swap_amount_out_for_buy(reserve, amount_in, liquidity).unwrap();
+        let expected = buy_reference(reserve, amount_in, liquidity) * BASE as f64;
+        // The reference is only exact to a fraction of a unit.
+        assert!(amount_out as f64 <= expected + 0.5, "{amount_out} exceeds {expected}");
+    }
+}
+
+#[test]
+fn sell_matches_reference() {
+    let mut rng = StdRng::seed_from_u64(2);
+    for _ in 0..CASES {
+        let (reserve, _, liquidity) = random_pool(&mut rng);
+        let amount_in = rng.gen_range(1..5 * liquidity);
+        let amount_out = calculate_swap_amount_out_for_sell(reserve, amount_in, liquidity).unwrap();
+        assert_close(amount_out, sell_reference(reserve, amount_in, liquidity));
+        assert!(amount_out <= amount_in);
+    }
+}
+
+#[test]
+fn spot_prices_round_trip() {
+    let mut rng = StdRng::seed_from_u64(3);
+    for _ in 0..CASES {
+        let (reserve, other, liquidity) = random_pool(&mut rng);
+        let price = calculate_spot_price(reserve, liquidity).unwrap();
+        assert_close(price, (-to_f64(reserve) / to_f64(liquidity)).exp());
+        // The rounding of the price to 10 decimals changes the reserve by up to `b / p` units.
+        let actual = calculate_reserve_from_spot_price(liquidity, price).unwrap();
+        assert!(actual.abs_diff(reserve) <= liquidity / price + 1, "{actual} != {reserve}");
+        let sum = price + calculate_spot_price(other, liquidity).unwrap();
+        assert!(sum.abs_diff(BASE) <= 2, "prices sum to {sum}");
+    }
+}
+
+#[test]
+fn prices_sum_to_one_after_trades() {
+    let mut rng = StdRng::seed_from_u64(4);
+    for _ in 0..CASES {
+        let (reserve, other, liquidity) = random_pool(&mut rng);
+        let amount_in = rng.gen_range(1..liquidity);
+
+        // Buying adds `amount_in` complete sets to the pool and takes out `amount_out + amount_in`.
+        let amount_out = calculate_swap_amount_out_for_buy(reserve, amount_in, liquidity).unwrap();
+        let price = calculate_spot_price(reserve - amount_out, liquidity).unwrap()
+            + calculate_spot_price(other + amount_in, liquidity).unwrap();
+        assert!(price.abs_diff(BASE) <= 10, "prices sum to {price} after buying");
+
+        // Selling adds `amount_in` to the pool and sells `amount_out` complete sets.
+        let amount_out = calculate_swap_amount_out_for_sell(reserve, amount_in, liquidity).unwrap();
+        let price = calculate_spot_price(reserve + amount_in - amount_out, liquidity).unwrap()
+            + calculate_spot_price(other - amount_out, liquidity).unwrap();
+        assert!(price.abs_diff(BASE) <= 10, "prices sum to {price} after selling");
+    }
+}
+
+#[test]
+fn round_trips_do_not_make_a_profit() {
+    let mut rng = StdRng::seed_from_u64(5);
+    for _ in 0..CASES {
+        let (reserve, _, liquidity) = random_pool(&mut rng);
+        let amount_in = rng.gen_range(1..liquidity);
+        let bought = calculate_swap_amount_out_for_buy(reserve, amount_in, liquidity).unwrap();
+        let received = calculate_swap_amount_out_for_sell(
+            reserve - bought,
+            bought + amount_in,
+            liquidity,
+        )
+        .unwrap();
+        assert!(received <= amount_in, "{amount_in} returned {received}");
+        assert!(amount_in - received <= 10, "{amount_in} returned only {received}");
+    }
+}
+
+#[test]
+fn computes_reserves_from_spot_prices() {
+    let mut rng = StdRng::seed_from_u64(6);
+    for _ in 0..CASES {
+        let amount = rng.gen_range(BASE..10_000 * BASE);
+        let first = rng.gen_range(BASE / 100..BASE / 2);
+        let second = rng.gen_range(BASE / 100..BASE - first);
+        let prices = [first, second, BASE - first - second];
+
+        let (reserves, liquidity) = calculate_reserves_from_spot_prices(amount, &prices).unwrap();
+        assert_eq!(reserves.iter().max(), Some(&amount));
+        for (reserve, price) in reserves.iter().zip(prices) {
+            let actual = calculate_spot_price(*reserve, liquidity).unwrap();
+            assert!(actual.abs_diff(price) <= 2, "{actual} != {price}");
+        }
+    }
+}
+
+#[test]
+fn bounds_the_deviation() {
+    assert_eq!(max_deviation(0), 10);
+    assert_eq!(max_deviation(10 * BASE), 10);
+    assert_eq!(max_deviation(100 * BASE), 100);
+    assert_eq!(max_deviation(1_000_000 * BASE), 1_000_000);
+}
+
+#[test]
+fn rejects_invalid_input() {
+    assert_eq!(calculate_spot_price(BASE, 0), Err(NeoSwapsError::ZeroAmount));
+    assert_eq!(
+        calculate_reserve_from_spot_price(LIQUIDITY, 0),
+        Err(NeoSwapsError::InvalidSpotPrices)
+    );
+    assert_eq!(
+        calculate_reserve_from_spot_price(LIQUIDITY, BASE + 1),
+        Err(NeoSwapsError::InvalidSpotPrices)
+    );
+    assert_eq!(calculate_liquidity_parameter(BASE, BASE), Err(NeoSwapsError::InvalidSpotPrices));
+    assert_eq!(calculate_reserves_from_spot_prices(BASE, &[]), Err(NeoSwapsError::IncorrectVecLen));
+    assert_eq!(
+        calculate_swap_amount_out_for_buy(BASE, 1_000 * LIQUIDITY, LIQUIDITY),
+        Err(NeoSwapsError::NumericalLimits)
+    );
+}
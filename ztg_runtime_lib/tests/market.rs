@@ -0,0 +1,194 @@
+use ink::primitives::AccountId;
+use sp_runtime::Perbill;
+use ztg_runtime_lib::{lmsr::BASE, market::*, primitives::*, runtime_structs::*, ss58};
+
+const ORACLE: [u8; 32] = [4; 32];
+
+fn builder(market_type: MarketType) -> MarketBuilder {
+    MarketBuilder::new(
+        AccountId::from(ORACLE),
+        MarketPeriod::Block(100..1_000),
+        MultiHash::Sha3_384([1; 50]),
+        market_type,
+    )
+}
+
+fn deadlines(grace_period: u64, oracle_duration: u64, dispute_duration: u64) -> Deadlines {
+    Deadlines { grace_period, oracle_duration, dispute_duration }
+}
+
+#[test]
+fn builds_create_market() {
+    let limits = MarketLimits::default();
+    assert_eq!(
+        builder(MarketType::Categorical(3))
+            .creator_fee(Perbill::from_parts(5_000_000))
+            .scoring_rule(ScoringRule::Parimutuel)
+            .create_market(),
+        Ok(RuntimeCall::PredictionMarkets(PredictionMarketsCall::CreateMarket {
+            base_asset: ZeitgeistAsset::Ztg,
+            creator_fee: Perbill::from_parts(5_000_000),
+            oracle: AccountId::from(ORACLE),
+            period: MarketPeriod::Block(100..1_000),
+            deadlines: deadlines(0, limits.min_oracle_duration, limits.min_dispute_duration),
+            metadata: MultiHash::Sha3_384([1; 50]),
+            creation: MarketCreation::Permissionless,
+            market_type: MarketType::Categorical(3),
+            dispute_mechanism: Some(MarketDisputeMechanism::Court),
+            scoring_rule: ScoringRule::Parimutuel,
+        }))
+    );
+}
+
+#[test]
+fn builds_edit_market() {
+    let call = builder(MarketType::Scalar(0..=100))
+        .base_asset(ZeitgeistAsset::ForeignAsset(1))
+        .creation(MarketCreation::Advised)
+        .dispute_mechanism(None)
+        .deadlines(deadlines(10, 500, 0))
+        .edit_market(5)
+        .unwrap();
+    let RuntimeCall::PredictionMarkets(PredictionMarketsCall::EditMarket {
+        market_id, base_asset, dispute_mechanism, deadlines: edited, ..
+    }) = call
+    else {
+        panic!("not an edit: {call:?}");
+    };
+    assert_eq!(market_id, 5);
+    assert_eq!(base_asset, ZeitgeistAsset::ForeignAsset(1));
+    assert_eq!(dispute_mechanism, None);
+    assert_eq!(edited, deadlines(10, 500, 0));
+}
+
+#[test]
+fn builds_create_market_and_deploy_pool() {
+    let call = builder(MarketType::Scalar(0..=100))
+        .create_market_and_deploy_pool(100 * BASE, vec![BASE / 4, 3 * BASE / 4], BASE / 100)
+        .unwrap();
+    let RuntimeCall::PredictionMarkets(PredictionMarketsCall::CreateMarketAndDeployPool {
+        amount, spot_prices, swap_fee, ..
+    }) = call
+    else {
+        panic!("not a pool deployment: {call:?}");
+    };
+    assert_eq!((amount, spot_prices, swap_fee), (100 * BASE, vec![BASE / 4, 3 * BASE / 4], BASE / 100));
+}
+
+#[test]
+fn validates_the_market() {
+    let limits = MarketLimits::default();
+    let categorical = || builder(MarketType::Categorical(2));
+    let rejects = |builder: MarketBuilder, error| assert_eq!(builder.create_market(), Err(error));
+
+    rejects(
+        categorical().base_asset(ZeitgeistAsset::CategoricalOutcome(0, 1)),
+        MarketBuilderError::InvalidBaseAsset,
+    );
+    rejects(
+        categorical().creator_fee(Perbill::from_percent(2)),
+        MarketBuilderError::CreatorFeeTooHigh { max: limits.max_creator_fee },
+    );
+    rejects(
+        MarketBuilder::new(
+            AccountId::from(ORACLE),
+            MarketPeriod::Timestamp(5..5),
+            MultiHash::Sha3_384([1; 50]),
+            MarketType::Categorical(2),
+        ),
+        MarketBuilderError::EmptyPeriod,
+    );
+    rejects(
+        categorical().deadlines(deadlines(limits.max_grace_period + 1, 500, 5_000)),
+        MarketBuilderError::GracePeriodTooLong { max: limits.max_grace_period },
+    );
+    rejects(
+        categorical().deadlines(deadlines(0, limits.min_oracle_duration - 1, 5_000)),
+        MarketBuilderError::OracleDurationTooShort { min: limits.min_oracle_duration },
+    );
+    rejects(
+        categorical().deadlines(deadlines(0, limits.max_oracle_duration + 1, 5_000)),
+        MarketBuilderError::OracleDurationTooLong { max: limits.max_oracle_duration },
+    );
+    rejects(
+        categorical().deadlines(deadlines(0, 500, limits.min_dispute_duration - 1)),
+        MarketBuilderError::DisputeDurationTooShort { min: limits.min_dispute_duration },
+    );
+    rejects(
+        categorical().deadlines(deadlines(0, 500, limits.max_dispute_duration + 1)),
+        MarketBuilderError::DisputeDurationTooLong { max: limits.max_dispute_duration },
+    );
+    rejects(
+        categorical().creation(MarketCreation::Advised).dispute_mechanism(None),
+        MarketBuilderError::DisputeDurationOnTrustedMarket,
+    );
+    rejects(
+        categorical().dispute_mechanism(None).deadlines(deadlines(0, 500, 0)),
+        MarketBuilderError::TrustedMarketNotAdvised,
+    );
+    rejects(
+        builder(MarketType::Categorical(1)),
+        MarketBuilderError::NotEnoughCategories { min: limits.min_categories },
+    );
+    rejects(
+        builder(MarketType::Categorical(limits.max_categories + 1)),
+        MarketBuilderError::TooManyCategories { max: limits.max_categories },
+    );
+    rejects(builder(MarketType::Scalar(10..=10)), MarketBuilderError::InvalidScalarRange);
+    rejects(
+        builder(MarketType::Scalar(0..=10)).scoring_rule(ScoringRule::Parimutuel),
+        MarketBuilderError::ParimutuelNotCategorical,
+    );
+}
+
+#[test]
+fn validates_the_pool() {
+    let deploy = |builder: MarketBuilder, amount, spot_prices| {
+        builder.create_market_and_deploy_pool(amount, spot_prices, 0)
+    };
+    let categorical = || builder(MarketType::Categorical(3));
+    let prices = || vec![BASE / 2, BASE / 4, BASE / 4];
+
+    assert!(deploy(categorical(), BASE, prices()).is_ok());
+    assert_eq!(
+        deploy(categorical().scoring_rule(ScoringRule::Orderbook), BASE, prices()),
+        Err(MarketBuilderError::PoolRequiresLmsr)
+    );
+    assert_eq!(
+        deploy(categorical(), BASE, vec![BASE / 2, BASE / 2]),
+        Err(MarketBuilderError::SpotPriceCountMismatch { expected: 3 })
+    );
+    assert_eq!(
+        deploy(categorical(), BASE, vec![BASE / 2, BASE / 4, BASE / 5]),
+        Err(MarketBuilderError::SpotPricesNotSummingToOne)
+    );
+    assert_eq!(deploy(categorical(), 0, prices()), Err(MarketBuilderError::ZeroLiquidity));
+}
+
+#[test]
+fn validates_against_custom_limits() {
+    // The runtime allows up to 64 categories.
+    assert!(builder(MarketType::Categorical(64)).create_market().is_ok());
+    let limits = MarketLimits { max_categories: 10, ..MarketLimits::default() };
+    assert_eq!(
+        builder(MarketType::Categorical(11)).limits(limits).create_market(),
+        Err(MarketBuilderError::TooManyCategories { max: 10 })
+    );
+}
+
+#[test]
+fn accepts_ss58_oracles() {
+    let from_ss58 = |oracle: &str| {
+        MarketBuilder::from_ss58(
+            oracle,
+            MarketPeriod::Block(100..1_000),
+            MultiHash::Sha3_384([1; 50]),
+            MarketType::Categorical(2),
+        )
+    };
+    // The address of `ORACLE` on Zeitgeist and Battery Station.
+    let expected = builder(MarketType::Categorical(2));
+    assert_eq!(from_ss58(&ss58::encode(&AccountId::from(ORACLE), 73)), Ok(expected.clone()));
+    assert_eq!(from_ss58(&ss58::encode(&AccountId::from(ORACLE), 42)), Ok(expected));
+    assert_eq!(from_ss58("dE3p"), Err(ss58::Ss58Error::InvalidLength));
+}
@@ -0,0 +1,97 @@
+use ztg_runtime_lib::{market_metadata::*, primitives::MultiHash};
+
+const JSON: &str = r##"{"question":"Will it rain in Berlin tomorrow?","description":"Resolves to Yes if it rains.","slug":"rain-berlin","categories":[{"name":"Yes","ticker":"YES"},{"name":"No","ticker":"NO","color":"#ff0000"}],"tags":["Weather"]}"##;
+// sha3-384 of `JSON`, prefixed with the multicodec and length of sha3-384. Both values were
+// computed independently with Python's `hashlib` and `base64`.
+const MULTIHASH: &str = "15304bd25f86ba653988a92db4bda372b074f54e449427da5f741d7181ab8ba9cc78cb4a98865e1f58428ea38f54e5ec4fb8";
+const CID: &str = "bafkrkmcl2jpynotfhgekslnuxwrxfmdu6vhejfbh3jpxihlrqgvyxkompdfuvgeglypvqquouohvjzpmj64a";
+
+fn metadata() -> MarketMetadata {
+    MarketMetadata {
+        question: "Will it rain in Berlin tomorrow?".into(),
+        description: "Resolves to Yes if it rains.".into(),
+        slug: "rain-berlin".into(),
+        categories: vec![
+            CategoryMetadata { name: "Yes".into(), ticker: Some("YES".into()), ..Default::default() },
+            CategoryMetadata {
+                name: "No".into(),
+                ticker: Some("NO".into()),
+                color: Some("#ff0000".into()),
+                ..Default::default()
+            },
+        ],
+        tags: vec!["Weather".into()],
+        ..Default::default()
+    }
+}
+
+fn hex_multihash(multihash: &MultiHash) -> String {
+    let MultiHash::Sha3_384(bytes) = multihash;
+    hex::encode(bytes)
+}
+
+#[test]
+fn serializes_like_the_sdk() {
+    assert_eq!(metadata().to_json(), JSON);
+    assert_eq!(MarketMetadata::from_json(JSON).unwrap(), metadata());
+
+    let scalar = MarketMetadata { scalar_type: Some("number".into()), ..Default::default() };
+    assert_eq!(
+        scalar.to_json(),
+        r#"{"question":"","description":"","slug":"","categories":[],"scalarType":"number"}"#
+    );
+}
+
+#[test]
+fn hashes_metadata() {
+    assert_eq!(hex_multihash(&metadata().multihash()), MULTIHASH);
+    assert_eq!(metadata().cid(), CID);
+}
+
+#[test]
+fn hashes_the_fips_202_test_vector() {
+    // The sha3-384 digest of "abc" from the examples of FIPS 202.
+    let digest = "ec01498288516fc926459f58e2c6ad8df9b473cb0fc08c2596da7cf0e49be4b298d88cea927ac7f539f1edf228376d25";
+    let multihash = multihash(b"abc");
+    assert_eq!(hex_multihash(&multihash), format!("1530{digest}"));
+    assert_eq!(
+        multihash_to_cid(&multihash),
+        "bafkrkmhmafeyfccrn7esmrm7ldrmnlmn7g2hhsypycgclfw2ptyojg7ewkmnrdhksj5mp5jz6hw7ekbxnusq"
+    );
+}
+
+#[test]
+fn converts_cids() {
+    let multihash = multihash(b"");
+    let cid = "bafkrkmammotvxbc6j56qced5quxeyjefyunfbkvkst6gdgk6og565gb2flbxcobrezfnwr73npi6awgv6aca";
+    assert_eq!(multihash_to_cid(&multihash), cid);
+    assert_eq!(cid_to_multihash(cid), Ok(multihash));
+    assert_eq!(hex_multihash(&cid_to_multihash(CID).unwrap()), MULTIHASH);
+}
+
+#[test]
+fn converts_uppercase_cids() {
+    let cid = "BAFKRKMHMAFEYFCCRN7ESMRM7LDRMNLMN7G2HHSYPYCGCLFW2PTYOJG7EWKMNRDHKSJ5MP5JZ6HW7EKBXNUSQ";
+    assert_eq!(cid_to_multihash(cid), Ok(multihash(b"abc")));
+    // The case is part of the multibase and must not change.
+    assert_eq!(cid_to_multihash(&cid.replacen('A', "a", 1)), Err(CidError::InvalidBase32));
+    assert_eq!(cid_to_multihash(&CID.to_uppercase()[1..]), Err(CidError::UnsupportedMultibase));
+}
+
+#[test]
+fn rejects_unsupported_cids() {
+    assert_eq!(
+        cid_to_multihash("QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG"),
+        Err(CidError::UnsupportedMultibase)
+    );
+    assert_eq!(cid_to_multihash("bafk1"), Err(CidError::InvalidBase32));
+    // The same content, stored as dag-pb.
+    assert_eq!(
+        cid_to_multihash(
+            "bafybkmammotvxbc6j56qced5quxeyjefyunfbkvkst6gdgk6og565gb2flbxcobrezfnwr73npi6awgv6aca"
+        ),
+        Err(CidError::UnsupportedCodec)
+    );
+    assert_eq!(cid_to_multihash(&CID[..20]), Err(CidError::UnsupportedHash));
+    assert_eq!(cid_to_multihash("baa"), Err(CidError::UnsupportedVersion));
+}
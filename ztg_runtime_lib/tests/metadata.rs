@@ -0,0 +1,160 @@
+mod common;
+
+use frame_metadata::{
+    v14::{ExtrinsicMetadata, PalletCallMetadata, PalletConstantMetadata, PalletMetadata, RuntimeMetadataV14},
+    RuntimeMetadata, RuntimeMetadataPrefixed, META_RESERVED,
+};
+use scale::Encode;
+use scale_info::{meta_type, MetaType, TypeInfo};
+use ztg_runtime_lib::{
+    metadata::{Mismatch, RuntimeCalls},
+    network::{Network, Target},
+};
+
+/// The calls of zrml-styx, as they appear in the runtime.
+#[allow(non_camel_case_types, dead_code)]
+#[derive(TypeInfo)]
+enum StyxCall {
+    #[codec(index = 0)]
+    cross,
+    #[codec(index = 1)]
+    set_burn_amount {
+        #[codec(compact)]
+        amount: u128,
+    },
+}
+
+/// The calls of zrml-authorized, diverged from this library.
+#[allow(non_camel_case_types, dead_code)]
+#[derive(TypeInfo)]
+enum AuthorizedCall {
+    #[codec(index = 1)]
+    authorize_market_outcome { outcome: u8, market_id: u128 },
+}
+
+/// The calls of zrml-global-disputes, diverged from this library.
+#[allow(non_camel_case_types, dead_code)]
+#[derive(TypeInfo)]
+enum GlobalDisputesCall {
+    #[codec(index = 0)]
+    add_vote_outcome { market_id: u128, outcome: u8 },
+    #[codec(index = 5)]
+    refund_vote_fees {
+        #[codec(compact)]
+        market_id: u128,
+    },
+}
+
+fn pallet(name: &'static str, index: u8, calls: MetaType) -> PalletMetadata {
+    PalletMetadata {
+        name,
+        storage: None,
+        calls: Some(PalletCallMetadata { ty: calls }),
+        event: None,
+        constants: vec![],
+        error: None,
+        index,
+    }
+}
+
+fn runtime_calls(pallets: Vec<PalletMetadata>) -> RuntimeCalls {
+    let metadata = RuntimeMetadataV14::new(
+        pallets,
+        ExtrinsicMetadata { ty: meta_type::<()>(), version: 4, signed_extensions: vec![] },
+        meta_type::<()>(),
+    );
+    let bytes = RuntimeMetadataPrefixed(META_RESERVED, RuntimeMetadata::V14(metadata)).encode();
+    RuntimeCalls::decode(&bytes).unwrap()
+}
+
+fn mismatches_of(calls: &RuntimeCalls, pallet: &str) -> Vec<Mismatch> {
+    calls
+        .verify()
+        .into_iter()
+        .filter(|m| match m {
+            Mismatch::MissingPallet { pallet: p }
+            | Mismatch::PalletIndex { pallet: p, .. }
+            | Mismatch::MissingCall { pallet: p, .. }
+            | Mismatch::CallIndex { pallet: p, .. }
+            | Mismatch::Fields { pallet: p, .. }
+            | Mismatch::Compact { pallet: p, .. } => p == pallet,
+        })
+        .collect()
+}
+
+#[test]
+fn matching_pallet_has_no_mismatches() {
+    let calls = runtime_calls(vec![pallet("Styx", <Target as Network>::STYX, meta_type::<StyxCall>())]);
+    assert_eq!(mismatches_of(&calls, "Styx"), vec![]);
+    assert!(mismatches_of(&calls, "Court")
+        .contains(&Mismatch::MissingPallet { pallet: "Court".into() }));
+}
+
+#[test]
+fn reports_index_and_field_mismatches() {
+    let calls = runtime_calls(vec![
+        pallet("Styx", 3, meta_type::<StyxCall>()),
+        pallet("Authorized", <Target as Network>::AUTHORIZED, meta_type::<AuthorizedCall>()),
+        pallet("GlobalDisputes", <Target as Network>::GLOBAL_DISPUTES, meta_type::<GlobalDisputesCall>()),
+    ]);
+
+    assert_eq!(
+        mismatches_of(&calls, "Styx"),
+        vec![Mismatch::PalletIndex { pallet: "Styx".into(), ours: <Target as Network>::STYX, runtime: 3 }]
+    );
+    assert_eq!(
+        mismatches_of(&calls, "Authorized"),
+        vec![
+            Mismatch::CallIndex {
+                pallet: "Authorized".into(),
+                call: "authorize_market_outcome".into(),
+                ours: 0,
+                runtime: 1,
+            },
+            Mismatch::Fields {
+                pallet: "Authorized".into(),
+                call: "authorize_market_outcome".into(),
+                ours: vec!["market_id".into(), "outcome".into()],
+                runtime: vec!["outcome".into(), "market_id".into()],
+            },
+        ]
+    );
+
+    let global_disputes = mismatches_of(&calls, "GlobalDisputes");
+    assert!(global_disputes.contains(&Mismatch::Compact {
+        pallet: "GlobalDisputes".into(),
+        call: "add_vote_outcome".into(),
+        field: "market_id".into(),
+        ours: true,
+        runtime: false,
+    }));
+    assert!(global_disputes.contains(&Mismatch::MissingCall {
+        pallet: "GlobalDisputes".into(),
+        call: "purge_outcomes".into(),
+    }));
+    assert!(!global_disputes.iter().any(|m| matches!(
+        m,
+        Mismatch::CallIndex { call, .. } | Mismatch::Compact { call, .. } if call == "refund_vote_fees"
+    )));
+}
+
+/// Verifies against `metadata/zeitgeist.scale`, or the file in `ZTG_METADATA` if it is set.
+#[test]
+fn runtime_call_matches_metadata_file() {
+    let mismatches = common::runtime().verify();
+    let report: Vec<String> = mismatches.iter().map(ToString::to_string).collect();
+    assert!(report.is_empty(), "{}", report.join("\n"));
+}
+
+#[test]
+fn reads_the_spec_version() {
+    let mut system = pallet("System", 0, meta_type::<()>());
+    system.constants = vec![PalletConstantMetadata {
+        name: "Version",
+        ty: meta_type::<()>(),
+        value: (String::from("zeitgeist"), String::from("zeitgeist"), 1u32, 50u32, 0u32).encode(),
+        docs: vec![],
+    }];
+    assert_eq!(runtime_calls(vec![system]).spec, Some(("zeitgeist".into(), 50)));
+    assert_eq!(runtime_calls(vec![]).spec, None);
+}
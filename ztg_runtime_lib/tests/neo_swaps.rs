@@ -0,0 +1,97 @@
+use sp_runtime::Perbill;
+use ztg_runtime_lib::{errors::NeoSwapsError, lmsr::*, primitives::*, runtime_structs::*};
+
+const MARKET_ID: MarketId = 3;
+/// The liquidity parameter at which a reserve of 10 has a spot price of 1/2.
+const LIQUIDITY: Balance = 144_269_504_088;
+const YES: ZeitgeistAsset = ZeitgeistAsset::CategoricalOutcome(MARKET_ID, 0);
+const NO: ZeitgeistAsset = ZeitgeistAsset::CategoricalOutcome(MARKET_ID, 1);
+
+fn pool(swap_fee: Balance) -> NeoSwapsPool {
+    NeoSwapsPool {
+        account_id: [9; 32].into(),
+        reserves: vec![(YES, 10 * BASE), (NO, 10 * BASE)],
+        collateral: ZeitgeistAsset::Ztg,
+        liquidity_parameter: LIQUIDITY,
+        swap_fee,
+    }
+}
+
+#[test]
+fn quotes_trades_without_fees() {
+    let pool = pool(0);
+    assert_eq!(pool.asset_count(), 2);
+    assert!(pool.spot_price(&YES).unwrap().abs_diff(BASE / 2) <= 1);
+
+    // Buying also returns the complete sets bought with `amount_in`.
+    let bought = pool.quote_buy(&YES, 10 * BASE, Perbill::zero()).unwrap();
+    assert!(bought.abs_diff(10 * BASE + 58_496_250_072) <= 10);
+    let sold = pool.quote_sell(&YES, 10 * BASE, Perbill::zero()).unwrap();
+    assert!(sold.abs_diff(41_503_749_928) <= 10);
+}
+
+#[test]
+fn deducts_fees_like_the_pallet() {
+    let pool = pool(BASE / 100);
+    let creator_fee = Perbill::from_percent(1);
+
+    // The fees are taken from the collateral paid in ...
+    let amount_in_minus_fees = 10 * BASE - 2 * BASE / 10;
+    assert_eq!(
+        pool.quote_buy(&YES, 10 * BASE, creator_fee),
+        Ok(calculate_swap_amount_out_for_buy(10 * BASE, amount_in_minus_fees, LIQUIDITY).unwrap()
+            + amount_in_minus_fees)
+    );
+
+    // ... and from the collateral paid out.
+    let amount_out = calculate_swap_amount_out_for_sell(10 * BASE, 10 * BASE, LIQUIDITY).unwrap();
+    assert_eq!(
+        pool.quote_sell(&YES, 10 * BASE, creator_fee),
+        Ok(amount_out - 2 * (amount_out / 100))
+    );
+}
+
+#[test]
+fn builds_buys_with_max_slippage() {
+    let pool = pool(BASE / 100);
+    let amount_out = pool.quote_buy(&NO, 5 * BASE, Perbill::zero()).unwrap();
+    assert_eq!(
+        pool.buy_with_max_slippage(MARKET_ID, NO, 5 * BASE, Perbill::zero(), 150),
+        Ok(RuntimeCall::NeoSwaps(NeoSwapsCall::Buy {
+            market_id: MARKET_ID,
+            asset_count: 2,
+            asset_out: NO,
+            amount_in: 5 * BASE,
+            min_amount_out: (amount_out - max_deviation(amount_out)) * 9_850 / 10_000,
+        }))
+    );
+}
+
+#[test]
+fn builds_sells_with_max_slippage() {
+    let pool = pool(BASE / 100);
+    let amount_out = pool.quote_sell(&YES, 5 * BASE, Perbill::from_percent(2)).unwrap();
+    assert_eq!(
+        pool.sell_with_max_slippage(MARKET_ID, YES, 5 * BASE, Perbill::from_percent(2), 0),
+        Ok(RuntimeCall::NeoSwaps(NeoSwapsCall::Sell {
+            market_id: MARKET_ID,
+            asset_count: 2,
+            asset_in: YES,
+            amount_in: 5 * BASE,
+            // Without slippage, only the deviation of the LMSR math is allowed for.
+            min_amount_out: amount_out - max_deviation(amount_out),
+        }))
+    );
+}
+
+#[test]
+fn rejects_invalid_trades() {
+    let pool = pool(0);
+    let unknown = ZeitgeistAsset::CategoricalOutcome(MARKET_ID, 2);
+    assert_eq!(pool.quote_buy(&unknown, BASE, Perbill::zero()), Err(NeoSwapsError::AssetNotFound));
+    assert_eq!(pool.quote_sell(&YES, 0, Perbill::zero()), Err(NeoSwapsError::ZeroAmount));
+    assert_eq!(
+        pool.buy_with_max_slippage(MARKET_ID, YES, BASE, Perbill::zero(), 10_001),
+        Err(NeoSwapsError::NumericalLimits)
+    );
+}
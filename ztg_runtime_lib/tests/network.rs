@@ -0,0 +1,27 @@
+use scale::{Decode, Encode};
+use ztg_runtime_lib::{
+    network::{Network, Target},
+    runtime_structs::*,
+};
+
+#[test]
+fn runtime_call_uses_target_pallet_indices() {
+    let call = RuntimeCall::PredictionMarkets(PredictionMarketsCall::Dispute { market_id: 1 });
+    assert_eq!(call.pallet_index(), <Target as Network>::PREDICTION_MARKETS);
+    assert_eq!(call.encode(), [<Target as Network>::PREDICTION_MARKETS, 6, 4]);
+    assert_eq!(RuntimeCall::NETWORK, <Target as Network>::NAME);
+}
+
+#[test]
+fn runtime_call_decodes_with_target_pallet_indices() {
+    let encoded = RuntimeCall::Styx(StyxCall::SetBurnAmount { amount: 5 }).encode();
+    let decoded = RuntimeCall::decode(&mut &encoded[..]).unwrap();
+    assert!(matches!(
+        decoded,
+        RuntimeCall::Styx(StyxCall::SetBurnAmount { amount: 5 })
+    ));
+    assert_eq!(decoded.encode(), encoded);
+
+    // No pallet is registered at index 0.
+    assert!(RuntimeCall::decode(&mut &[0u8, 0][..]).is_err());
+}
@@ -0,0 +1,126 @@
+use ztg_runtime_lib::{lmsr::BASE, orderbook::*, primitives::*, runtime_structs::*};
+
+const MARKET_ID: MarketId = 2;
+const OUTCOME: ZeitgeistAsset = ZeitgeistAsset::CategoricalOutcome(MARKET_ID, 1);
+
+fn order(side: Side, price: Balance, size: Balance) -> LimitOrder {
+    LimitOrder { side, outcome: OUTCOME, base_asset: ZeitgeistAsset::Ztg, price, size }
+}
+
+fn place(maker: (ZeitgeistAsset, Balance), taker: (ZeitgeistAsset, Balance)) -> RuntimeCall {
+    RuntimeCall::Orderbook(OrderbookCall::PlaceOrder {
+        market_id: MARKET_ID,
+        maker_asset: maker.0,
+        maker_amount: maker.1,
+        taker_asset: taker.0,
+        taker_amount: taker.1,
+    })
+}
+
+fn on_chain(call: RuntimeCall) -> Order {
+    let RuntimeCall::Orderbook(OrderbookCall::PlaceOrder {
+        market_id, maker_asset, maker_amount, taker_asset, taker_amount,
+    }) = call
+    else {
+        panic!("not an order: {call:?}");
+    };
+    Order { market_id, maker: [0; 32].into(), maker_asset, maker_amount, taker_asset, taker_amount }
+}
+
+#[test]
+fn converts_price_and_size_to_amounts() {
+    assert_eq!(
+        order(Side::Buy, 6_500_000_000, 10 * BASE).place(MARKET_ID),
+        Ok(place((ZeitgeistAsset::Ztg, 65 * BASE / 10), (OUTCOME, 10 * BASE)))
+    );
+    assert_eq!(
+        order(Side::Sell, 6_500_000_000, 10 * BASE).place(MARKET_ID),
+        Ok(place((OUTCOME, 10 * BASE), (ZeitgeistAsset::Ztg, 65 * BASE / 10)))
+    );
+}
+
+#[test]
+fn rounds_in_favor_of_the_maker() {
+    // 3 units at a third of a unit is worth exactly one unit.
+    let price = BASE / 3;
+    assert_eq!(order(Side::Buy, price, 3).value(3), Ok(0));
+    assert_eq!(order(Side::Sell, price, 3).value(3), Ok(1));
+    assert_eq!(order(Side::Buy, price, 3).place(MARKET_ID), Err(OrderError::ZeroAmount));
+
+    let buy = order(Side::Buy, price, 7 * BASE);
+    let read = LimitOrder::from_order(&on_chain(buy.place(MARKET_ID).unwrap())).unwrap();
+    assert!(read.price <= price);
+    let sell = order(Side::Sell, price, 7 * BASE);
+    let read = LimitOrder::from_order(&on_chain(sell.place(MARKET_ID).unwrap())).unwrap();
+    assert!(read.price >= price);
+}
+
+#[test]
+fn reads_orders_from_chain() {
+    for side in [Side::Buy, Side::Sell] {
+        let order = order(side, 2_500_000_000, 40 * BASE);
+        assert_eq!(LimitOrder::from_order(&on_chain(order.place(MARKET_ID).unwrap())), Ok(order));
+    }
+
+    let swap = on_chain(place((OUTCOME, BASE), (ZeitgeistAsset::CategoricalOutcome(MARKET_ID, 0), BASE)));
+    assert_eq!(LimitOrder::from_order(&swap).map(|_| ()), Err(OrderError::InvalidAssets));
+    let invalid = LimitOrder { base_asset: OUTCOME, ..order(Side::Buy, BASE, BASE) };
+    assert_eq!(invalid.place(MARKET_ID), Err(OrderError::InvalidAssets));
+}
+
+#[test]
+fn fills_orders_partially() {
+    let fill = |maker_partial_fill| {
+        Ok(RuntimeCall::Orderbook(OrderbookCall::FillOrder { order_id: 9, maker_partial_fill }))
+    };
+    // The partial fill is an amount of the maker asset.
+    let buy = order(Side::Buy, 2_500_000_000, 40 * BASE);
+    assert_eq!(buy.fill(9, Some(4 * BASE)), fill(Some(BASE)));
+    let sell = order(Side::Sell, 2_500_000_000, 40 * BASE);
+    assert_eq!(sell.fill(9, Some(4 * BASE)), fill(Some(4 * BASE)));
+
+    assert_eq!(sell.fill(9, None), fill(None));
+    assert_eq!(sell.fill(9, Some(40 * BASE)), fill(None));
+    assert_eq!(sell.fill(9, Some(41 * BASE)), Err(OrderError::FillExceedsSize));
+    assert_eq!(buy.fill(9, Some(1)), Err(OrderError::ZeroAmount));
+}
+
+#[test]
+fn tracks_open_orders() {
+    let mut orders = OpenOrders::new();
+    assert_eq!(orders.remove_all(), None);
+
+    orders.insert(1, order(Side::Buy, BASE / 2, 10 * BASE)).unwrap();
+    orders.insert(2, order(Side::Sell, BASE / 2, 10 * BASE)).unwrap();
+    orders.insert(3, order(Side::Sell, BASE / 4, 10 * BASE)).unwrap();
+    assert_eq!(orders.insert(3, order(Side::Buy, BASE, BASE)), Err(OrderError::AlreadyTracked));
+
+    orders.filled(1, 4 * BASE).unwrap();
+    assert_eq!(orders.get(1).map(|order| order.size), Some(6 * BASE));
+    assert_eq!(orders.filled(1, 7 * BASE), Err(OrderError::FillExceedsSize));
+    orders.filled(1, 6 * BASE).unwrap();
+    assert_eq!(orders.get(1), None);
+
+    let partially_filled = on_chain(order(Side::Sell, BASE / 2, 3 * BASE).place(MARKET_ID).unwrap());
+    orders.sync(2, Some(&partially_filled)).unwrap();
+    assert_eq!(orders.get(2).map(|order| order.size), Some(3 * BASE));
+    orders.sync(2, None).unwrap();
+    assert_eq!(orders.sync(2, None), Err(OrderError::NotTracked));
+
+    assert_eq!(
+        orders.remove(3),
+        Ok(RuntimeCall::Orderbook(OrderbookCall::RemoveOrder { order_id: 3 }))
+    );
+    assert!(orders.orders().is_empty());
+}
+
+#[test]
+fn removes_all_orders_at_once() {
+    let mut orders = OpenOrders::new();
+    for order_id in [4, 8] {
+        orders.insert(order_id, order(Side::Buy, BASE / 2, BASE)).unwrap();
+    }
+    let remove = |order_id| RuntimeCall::Orderbook(OrderbookCall::RemoveOrder { order_id });
+    assert_eq!(orders.remove_all(), Some(Batch::new().call(remove(4)).call(remove(8)).force_batch()));
+    assert!(orders.orders().is_empty());
+}
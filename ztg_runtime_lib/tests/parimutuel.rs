@@ -0,0 +1,97 @@
+use sp_runtime::Perbill;
+use ztg_runtime_lib::{
+    errors::ParimutuelError, lmsr::BASE, parimutuel::*, primitives::*, runtime_structs::*,
+};
+
+const MARKET_ID: MarketId = 7;
+
+/// A pot of a market with three outcomes and bets of 10 and 30 on outcome 0, 60 on outcome 1
+/// and none on outcome 2.
+fn pot() -> ParimutuelPot {
+    let mut pot = ParimutuelPot::new(3);
+    for (category, amount) in [(0, 10), (0, 30), (1, 60)] {
+        pot.bet(category, amount * BASE, Perbill::zero()).unwrap();
+    }
+    pot
+}
+
+#[test]
+fn buy_deducts_external_fees() {
+    let mut pot = ParimutuelPot::new(2);
+    assert_eq!(shares_for_bet(10 * BASE, Perbill::from_percent(1)), 99 * BASE / 10);
+    assert_eq!(pot.bet(1, 10 * BASE, Perbill::from_percent(1)), Ok(99 * BASE / 10));
+    assert_eq!(pot, ParimutuelPot { total: 99 * BASE / 10, outcome_totals: vec![0, 99 * BASE / 10] });
+    assert_eq!(pot.bet(2, BASE, Perbill::zero()), Err(ParimutuelError::InvalidOutcomeAsset));
+}
+
+#[test]
+fn claim_rewards_pays_a_share_of_the_pot() {
+    let pot = pot();
+    assert_eq!(pot.payoff_ratio(0), Ok(25 * BASE / 10));
+    assert_eq!(pot.reward(0, 10 * BASE), Ok(25 * BASE));
+    assert_eq!(pot.reward(0, 30 * BASE), Ok(75 * BASE));
+    assert_eq!(
+        pot.claim(MARKET_ID, 0, &[30 * BASE, 5 * BASE, 0]),
+        Ok((vec![RuntimeCall::Parimutuel(ParimutelCall::ClaimRewards { market_id: MARKET_ID })], 75 * BASE))
+    );
+}
+
+#[test]
+fn claim_rewards_rounds_like_the_pallet() {
+    // 10 / 3 is rounded to the nearest unit, then multiplied and rounded again.
+    let pot = ParimutuelPot { total: 10 * BASE, outcome_totals: vec![3 * BASE, 7 * BASE] };
+    assert_eq!(pot.payoff_ratio(0), Ok(33_333_333_333));
+    assert_eq!(pot.reward(0, BASE), Ok(33_333_333_333));
+    // Rounding up would pay out more than the pot holds.
+    let pot = ParimutuelPot { total: 2 * BASE, outcome_totals: vec![3 * BASE, 0] };
+    assert_eq!(pot.payoff_ratio(0), Ok(6_666_666_667));
+    assert_eq!(pot.reward(0, 3 * BASE), Ok(2 * BASE));
+}
+
+#[test]
+fn claim_rewards_fails_without_winning_shares() {
+    let pot = pot();
+    assert_eq!(pot.reward(0, 0), Err(ParimutuelError::NoWinningShares));
+    assert_eq!(pot.reward(0, 41 * BASE), Err(ParimutuelError::Unexpected));
+    assert_eq!(pot.reward(2, BASE), Err(ParimutuelError::NoRewardShareOutstanding));
+    assert_eq!(pot.claim(MARKET_ID, 1, &[BASE]), Err(ParimutuelError::NoWinningShares));
+    assert_eq!(pot.reward(3, BASE), Err(ParimutuelError::InvalidOutcomeAsset));
+}
+
+#[test]
+fn claim_refunds_works_if_no_one_bet_on_the_winner() {
+    let pot = pot();
+    assert_eq!(pot.is_refundable(2), Ok(true));
+    assert_eq!(pot.refund(2, 10 * BASE), Ok(10 * BASE));
+    assert_eq!(pot.refund(2, 0), Err(ParimutuelError::RefundableBalanceIsZero));
+
+    let refund = |category| {
+        RuntimeCall::Parimutuel(ParimutelCall::ClaimRefunds {
+            refund_asset: ZeitgeistAsset::ParimutuelShare(MARKET_ID, category),
+        })
+    };
+    assert_eq!(
+        pot.claim(MARKET_ID, 2, &[10 * BASE, 60 * BASE, 0]),
+        Ok((vec![refund(0), refund(1)], 70 * BASE))
+    );
+    assert_eq!(pot.claim(MARKET_ID, 2, &[0, 0]), Err(ParimutuelError::RefundableBalanceIsZero));
+}
+
+#[test]
+fn claim_refunds_fails_if_someone_bet_on_the_winner() {
+    let pot = pot();
+    assert_eq!(pot.is_refundable(1), Ok(false));
+    assert_eq!(pot.refund(1, 10 * BASE), Err(ParimutuelError::RefundNotAllowed));
+}
+
+#[test]
+fn rewards_split_the_pot() {
+    let mut pot = ParimutuelPot::new(2);
+    let bets = [3, 7, 11, 13, 17, 19, 23].map(|amount| amount * BASE / 3);
+    let shares = bets.map(|amount| pot.bet(0, amount, Perbill::from_percent(2)).unwrap());
+    pot.bet(1, 5 * BASE, Perbill::from_percent(2)).unwrap();
+
+    // Each reward is off by at most one unit of rounding.
+    let paid: Balance = shares.iter().map(|balance| pot.reward(0, *balance).unwrap()).sum();
+    assert!(paid.abs_diff(pot.total) <= shares.len() as Balance, "paid {paid} of {}", pot.total);
+}
@@ -0,0 +1,148 @@
+use ink::primitives::AccountId;
+use scale::{Decode, Encode};
+use sp_runtime::Perbill;
+use ztg_runtime_lib::primitives::*;
+
+/// Decodes `hex` into `T`, checks it against `expected` and that it encodes back to the same bytes.
+fn assert_round_trip<T: Encode + Decode + PartialEq + core::fmt::Debug>(hex: &str, expected: T) {
+    let bytes = hex::decode(hex).unwrap();
+    let decoded = T::decode(&mut &bytes[..]).unwrap();
+    assert_eq!(decoded, expected);
+    assert_eq!(decoded.encode(), bytes);
+}
+
+fn metadata() -> Vec<u8> {
+    let mut metadata = vec![0x15, 0x30];
+    metadata.extend([0xaa; 48]);
+    metadata
+}
+
+#[test]
+fn market_status_round_trip() {
+    assert_round_trip("00", MarketStatus::Proposed);
+    assert_round_trip("01", MarketStatus::Active);
+    assert_round_trip("02", MarketStatus::Closed);
+    assert_round_trip("03", MarketStatus::Reported);
+    assert_round_trip("04", MarketStatus::Disputed);
+    assert_round_trip("05", MarketStatus::Resolved);
+    assert!(MarketStatus::decode(&mut &[6u8][..]).is_err());
+}
+
+#[test]
+fn report_round_trip() {
+    assert_round_trip(
+        "fa000000000000000202020202020202020202020202020202020202020202020202020202020202000100",
+        Report {
+            at: 250,
+            by: AccountId::from([2; 32]),
+            outcome: OutcomeReport::Categorical(1),
+        },
+    );
+}
+
+#[test]
+fn early_close_round_trip() {
+    assert_round_trip(
+        "0101e803000000000000d00700000000000001e803000000000000dc0500000000000000",
+        Some(EarlyClose {
+            old: MarketPeriod::Timestamp(1000..2000),
+            new: MarketPeriod::Timestamp(1000..1500),
+            state: EarlyCloseState::ScheduledAsMarketCreator,
+        }),
+    );
+}
+
+#[test]
+fn market_round_trip() {
+    let creator = AccountId::from([1; 32]);
+    let oracle = AccountId::from([2; 32]);
+    assert_round_trip(
+        concat!(
+            "04",
+            "0101010101010101010101010101010101010101010101010101010101010101",
+            "00",
+            "80969800",
+            "0202020202020202020202020202020202020202020202020202020202020202",
+            "c81530aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+            "000200",
+            "006400000000000000c800000000000000",
+            "000000000000000080700000000000008070000000000000",
+            "00",
+            "03",
+            "01fa000000000000000202020202020202020202020202020202020202020202020202020202020202000100",
+            "00",
+            "0101",
+            "010101010101010101010101010101010101010101010101010101010101010101",
+            "0010a5d4e80000000000000000000000",
+            "00",
+            "010101010101010101010101010101010101010101010101010101010101010101",
+            "005039278c0400000000000000000000",
+            "01",
+            "00000000",
+            "00",
+        ),
+        Market {
+            base_asset: ZeitgeistAsset::Ztg,
+            creator,
+            creation: MarketCreation::Permissionless,
+            creator_fee: Perbill::from_percent(1),
+            oracle,
+            metadata: metadata(),
+            market_type: MarketType::Categorical(2),
+            period: MarketPeriod::Block(100..200),
+            deadlines: Deadlines {
+                grace_period: 0,
+                oracle_duration: 28_800,
+                dispute_duration: 28_800,
+            },
+            scoring_rule: ScoringRule::Lmsr,
+            status: MarketStatus::Reported,
+            report: Some(Report {
+                at: 250,
+                by: oracle,
+                outcome: OutcomeReport::Categorical(1),
+            }),
+            resolved_outcome: None,
+            dispute_mechanism: Some(MarketDisputeMechanism::Court),
+            bonds: MarketBonds {
+                creation: Some(Bond {
+                    who: creator,
+                    value: 100 * 10u128.pow(10),
+                    is_settled: false,
+                }),
+                oracle: Some(Bond {
+                    who: creator,
+                    value: 500 * 10u128.pow(10),
+                    is_settled: true,
+                }),
+                ..Default::default()
+            },
+            early_close: None,
+        },
+    );
+}
+
+#[test]
+fn ztg_address_converts_accounts() {
+    let alice = hex::decode("d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d").unwrap();
+    let bytes: [u8; 32] = alice.try_into().unwrap();
+    let address = ZtgAddress::from(bytes);
+    assert_eq!(address, ZtgAddress::from(AccountId::from(bytes)));
+    assert_eq!(address.account_id(), Some(&AccountId::from(bytes)));
+    assert_eq!("dE3pPiRvdKqPD5bUDBu3Xpi83McE3Zf3UG8CbhWBQfvUywd7U".parse(), Ok(address.clone()));
+    assert_eq!("5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY".parse(), Ok(address.clone()));
+    // The last character is part of the checksum.
+    assert!("dE3pPiRvdKqPD5bUDBu3Xpi83McE3Zf3UG8CbhWBQfvUywd7V".parse::<ZtgAddress>().is_err());
+
+    // `MultiAddress::Id` of Alice.
+    assert_round_trip(
+        "00d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d",
+        address,
+    );
+}
+
+#[test]
+fn ztg_address_has_the_type_info_of_the_runtime() {
+    use scale_info::TypeInfo;
+    assert_eq!(ZtgAddress::type_info(), sp_runtime::MultiAddress::<AccountId, ()>::type_info());
+}
@@ -0,0 +1,75 @@
+use ink::primitives::AccountId;
+use ztg_runtime_lib::{
+    network::{Network, Target},
+    primitives::ZtgAddress,
+    ss58::*,
+};
+
+const ALICE: [u8; 32] = [
+    0xd4, 0x35, 0x93, 0xc7, 0x15, 0xfd, 0xd3, 0x1c, 0x61, 0x14, 0x1a, 0xbd, 0x04, 0xa9, 0x9f, 0xd6,
+    0x82, 0x2c, 0x85, 0x58, 0x85, 0x4c, 0xcd, 0xe3, 0x9a, 0x56, 0x84, 0xe7, 0xa5, 0x6d, 0xa2, 0x7d,
+];
+const ALICE_ZEITGEIST: &str = "dE3pPiRvdKqPD5bUDBu3Xpi83McE3Zf3UG8CbhWBQfvUywd7U";
+const ALICE_GENERIC: &str = "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY";
+const ALICE_KUSAMA: &str = "HNZata7iMYWmk5RvZRTiAsSDhV8366zq2YGb3tLH5Upf74F";
+
+/// Alice's address on the network selected with the network features.
+fn alice_on_target() -> &'static str {
+    match Target::SS58_PREFIX {
+        ZEITGEIST_PREFIX => ALICE_ZEITGEIST,
+        _ => ALICE_GENERIC,
+    }
+}
+
+#[test]
+fn encodes_addresses() {
+    let alice = AccountId::from(ALICE);
+    assert_eq!(encode(&alice, ZEITGEIST_PREFIX), ALICE_ZEITGEIST);
+    assert_eq!(encode(&alice, GENERIC_PREFIX), ALICE_GENERIC);
+    assert_eq!(encode(&alice, 2), ALICE_KUSAMA);
+    assert_eq!(
+        encode(&AccountId::from([0; 32]), ZEITGEIST_PREFIX),
+        "dDy29eXSKP4b9cyCi5QP7sLnTRt2WdwCWoymbLpagKZC8rQTr"
+    );
+    assert_eq!(
+        encode(&AccountId::from([0; 32]), GENERIC_PREFIX),
+        "5C4hrfjw9DjXZTzV3MwzrrAr9P1MJhSrvWGWqi1eSuyUpnhM"
+    );
+    assert_eq!(to_ss58(&alice), alice_on_target());
+}
+
+#[test]
+fn decodes_addresses() {
+    let alice = AccountId::from(ALICE);
+    assert_eq!(decode(ALICE_ZEITGEIST), Ok((alice, ZEITGEIST_PREFIX)));
+    assert_eq!(decode(ALICE_GENERIC), Ok((alice, GENERIC_PREFIX)));
+    assert_eq!(account_id(ALICE_ZEITGEIST), Ok(alice));
+}
+
+#[test]
+fn rejects_invalid_addresses() {
+    // The last character is part of the checksum.
+    assert_eq!(
+        decode("dE3pPiRvdKqPD5bUDBu3Xpi83McE3Zf3UG8CbhWBQfvUywd7V"),
+        Err(Ss58Error::InvalidChecksum)
+    );
+    assert_eq!(
+        decode("dE3pPiRvdKqPD5bUDBu3Xpi83McE3Zf3UG8CbhWBQfvUywd7"),
+        Err(Ss58Error::InvalidLength)
+    );
+    assert_eq!(decode(""), Err(Ss58Error::InvalidLength));
+    // `0`, `O`, `I` and `l` are not part of the alphabet.
+    assert_eq!(
+        decode("dE3pPiRvdKqPD5bUDBu3Xpi83McE3Zf3UG8CbhWBQfvUywd70"),
+        Err(Ss58Error::InvalidBase58)
+    );
+    assert_eq!(decode(ALICE_KUSAMA), Err(Ss58Error::UnsupportedPrefix(2)));
+}
+
+#[test]
+fn formats_ztg_addresses() {
+    let address: ZtgAddress = ALICE_GENERIC.parse().unwrap();
+    assert_eq!(address, ZtgAddress::from(ALICE));
+    assert_eq!(address.to_string(), alice_on_target());
+    assert_eq!(ALICE_KUSAMA.parse::<ZtgAddress>(), Err(Ss58Error::UnsupportedPrefix(2)));
+}
@@ -0,0 +1,41 @@
+use scale::{Decode, Encode};
+use ztg_runtime_lib::{inspect::inspect, runtime_structs::*};
+
+fn redeem_shares() -> PredictionMarketsCall {
+    PredictionMarketsCall::RedeemShares { market_id: 7 }
+}
+
+#[test]
+fn encodes_batches() {
+    let batch = || Batch::new().call(redeem_shares()).call(StyxCall::Cross);
+
+    // Utility, call index, two calls, `PredictionMarkets::redeem_shares`, `Styx::cross`.
+    assert_eq!(hex::encode(batch().batch().encode()), "1f0008390c1c3a00");
+    assert_eq!(hex::encode(batch().batch_all().encode()), "1f0208390c1c3a00");
+    assert_eq!(hex::encode(batch().force_batch().encode()), "1f0408390c1c3a00");
+}
+
+#[test]
+fn decodes_nested_batches() {
+    let inner = Batch::new().call(redeem_shares()).batch_all();
+    let outer = Batch::new().call(inner.clone()).call(StyxCall::Cross).force_batch();
+
+    let decoded = RuntimeCall::decode(&mut &outer.encode()[..]).unwrap();
+    assert_eq!(decoded, outer);
+    let RuntimeCall::Utility(UtilityCall::ForceBatch { calls }) = decoded else {
+        panic!("expected a force batch");
+    };
+    assert_eq!(calls, vec![inner, RuntimeCall::Styx(StyxCall::Cross)]);
+}
+
+#[test]
+fn rejects_batches_nested_too_deep() {
+    let nested = |depth| {
+        (0..depth).fold(RuntimeCall::Styx(StyxCall::Cross), |call, _| {
+            Batch::new().call(call).batch()
+        })
+    };
+    assert!(inspect(&nested(20).encode()).is_ok());
+    assert!(inspect(&nested(40).encode()).is_err());
+}
+